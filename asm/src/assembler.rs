@@ -1,81 +1,1989 @@
-use crate::{line::Line, parser::Parser};
+use crate::{
+    error::{AsmError, AsmWarning, Diagnostic, Span},
+    functions::{
+        canonical_instruction, convert_memory_operand, split_arguments, split_mnemonic,
+        split_operands,
+    },
+    line::Line,
+    parser::parse_line,
+    preprocessor::{Location, Preprocessor, SourceLine},
+    register::Register,
+    section::{Assembly, SectionTable},
+    symbol::SymbolTable,
+    syntax::Syntax,
+};
+use std::path::PathBuf;
+
+/// Max count of layout passes
+/// Instructions which are still in short forms after this are laid out in near forms
+const MAX_LAYOUT_PASSES: usize = 16;
+
+/// Instruction laid out in short form
+#[derive(Clone, Debug)]
+struct ShortForm {
+    /// Index of line
+    line: usize,
+    /// Index of section
+    section: usize,
+    /// Offset from start of section
+    offset: usize,
+    instruction: String,
+}
+
+/// Line kept across layout passes
+struct LaidOutLine<'a> {
+    line: Line<'a>,
+    /// If instruction is tried in short form
+    short: bool,
+    /// Instruction laid out by the previous pass
+    instruction: Option<LaidOutInstruction>,
+}
+
+impl LaidOutLine<'_> {
+    /// Lay out instruction in near form from the next pass
+    fn give_up_short_form(&mut self) {
+        self.short = false;
+        if self
+            .instruction
+            .as_ref()
+            .is_some_and(|instruction| instruction.short_form.is_some())
+        {
+            self.instruction = None;
+        }
+    }
+}
+
+/// Instruction matched by a layout pass
+/// Its length is reused while constants are substituted into the same canonical form
+struct LaidOutInstruction {
+    canonical: String,
+    len: usize,
+    short_form: Option<String>,
+}
 
 #[derive(Clone, Copy, Debug)]
 pub struct Asm<'a> {
     source: &'a str,
+    include_paths: &'a [PathBuf],
+    absolute_includes: bool,
+    syntax: Syntax,
 }
 
 impl<'a> Asm<'a> {
     pub fn new(source: &'a str) -> Self {
-        Asm { source: source }
-    }
-
-    pub fn labels(&self) -> Result<Vec<Label<'_>>, usize> {
-        let mut labels = Vec::new();
-        let parser = Parser::new(self.source);
-        let mut offset: usize = 0;
-        let mut line_count: usize = 0;
-
-        for line in parser {
-            line_count += 1;
-            match line {
-                Line::None => (),
-                Line::Label(s) => labels.push(Label {
-                    name: s,
-                    offset: offset,
-                }),
-                Line::AsmCommand(_) => todo!(),
-                Line::Instruction(_) => {
-                    if line.is_valid_instruction() {
-                        offset += line.machine_code_len();
-                    } else {
-                        return Err(line_count);
+        Asm {
+            source,
+            include_paths: &[],
+            absolute_includes: false,
+            syntax: Syntax::Intel,
+        }
+    }
+
+    /// Set syntax of source
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        Asm { syntax, ..self }
+    }
+
+    /// Set directories which .include and .incbin search files in
+    pub fn with_include_paths(self, include_paths: &'a [PathBuf]) -> Self {
+        Asm {
+            include_paths,
+            ..self
+        }
+    }
+
+    /// Allow .include and .incbin to read files by absolute paths
+    pub fn with_absolute_includes(self, absolute_includes: bool) -> Self {
+        Asm {
+            absolute_includes,
+            ..self
+        }
+    }
+
+    /// Get symbol table which contains constants and labels
+    pub fn symbols(&self) -> Result<SymbolTable, AsmError> {
+        let mut diagnostics = Vec::new();
+        let lines = self.preprocess(&mut diagnostics);
+        let (symbols, _, _, _) = self.layout(&lines, &mut diagnostics);
+        sort_diagnostics(&mut diagnostics);
+        match diagnostics.into_iter().find_map(Diagnostic::into_error) {
+            Some(error) => Err(error),
+            None => Ok(symbols),
+        }
+    }
+
+    fn preprocess(&self, diagnostics: &mut Vec<Diagnostic>) -> Vec<SourceLine> {
+        let mut preprocessor = Preprocessor::new();
+        for path in self.include_paths {
+            preprocessor.add_include_path(path);
+        }
+        preprocessor.set_absolute_includes(self.absolute_includes);
+        preprocessor.set_syntax(self.syntax);
+        let (lines, preprocessor_diagnostics) = preprocessor.process_all(self.source);
+        diagnostics.extend(preprocessor_diagnostics);
+        lines
+    }
+
+    /// Lay out lines into sections
+    /// Instructions start in short forms like rel8 and disp8, and grow into near forms if they
+    /// don't fit, so layout is repeated until no instruction grows
+    /// Labels and `$` are addresses in image like the assemble pass, so layout is also repeated
+    /// until sections start where the previous pass assumed
+    /// Lines are parsed once, and only instructions which change are matched again
+    /// Returns length of each line, which is None if the line has an error,
+    /// and if each line can be in short form
+    fn layout(
+        &self,
+        lines: &[SourceLine],
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> (SymbolTable, SectionTable, Vec<Option<usize>>, Vec<bool>) {
+        let mut laid_out: Vec<LaidOutLine> = lines
+            .iter()
+            .map(|source_line| LaidOutLine {
+                line: parse_line(source_line.text()),
+                short: true,
+                instruction: None,
+            })
+            .collect();
+        // The first pass assumes that all sections start at 0
+        let mut addresses = Vec::new();
+        let mut passes = 0;
+
+        loop {
+            passes += 1;
+            let mut pass_diagnostics = Vec::new();
+            let (mut symbols, sections, lens, short_forms) =
+                self.layout_pass(lines, &mut laid_out, &addresses, &mut pass_diagnostics);
+
+            let mut grown = false;
+            for short_form in short_forms {
+                let section = sections.get(short_form.section);
+                let address = section.address() + short_form.offset;
+                symbols.set_location(address);
+                symbols.set_section_start(section.address());
+                if Line::Instruction(&short_form.instruction)
+                    .machine_code(&symbols, address)
+                    .is_err()
+                {
+                    laid_out[short_form.line].give_up_short_form();
+                    grown = true;
+                }
+            }
+
+            // Sections which keep moving are reported as size differences by the assemble pass
+            let section_addresses = sections.addresses();
+            let converged = section_addresses
+                .iter()
+                .enumerate()
+                .all(|(index, address)| addresses.get(index).copied().unwrap_or(0) == *address);
+            if !grown && (converged || MAX_LAYOUT_PASSES < passes) {
+                diagnostics.extend(pass_diagnostics);
+                let short = laid_out.iter().map(|line| line.short).collect();
+                return (symbols, sections, lens, short);
+            }
+            addresses = section_addresses;
+            // Growing one instruction can make another one grow, so give up making them short
+            if passes == MAX_LAYOUT_PASSES {
+                laid_out
+                    .iter_mut()
+                    .for_each(LaidOutLine::give_up_short_form);
+            }
+        }
+    }
+
+    /// Lay out lines once assuming sections start at addresses
+    /// Returns instructions in short forms which must be checked if they fit
+    fn layout_pass(
+        &self,
+        lines: &[SourceLine],
+        laid_out: &mut [LaidOutLine],
+        addresses: &[usize],
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> (
+        SymbolTable,
+        SectionTable,
+        Vec<Option<usize>>,
+        Vec<ShortForm>,
+    ) {
+        let mut symbols = SymbolTable::new();
+        let mut sections = SectionTable::new();
+        let mut lens = Vec::new();
+        let mut short_forms = Vec::new();
+
+        for (index, (source_line, laid_out)) in lines.iter().zip(laid_out).enumerate() {
+            let offset = sections.current().size();
+            let section_start = addresses
+                .get(sections.current_index())
+                .copied()
+                .unwrap_or(0);
+            symbols.set_location(section_start + offset);
+            symbols.set_section_start(section_start);
+            let result = self.layout_line(
+                source_line.location(),
+                laid_out,
+                &mut symbols,
+                &mut sections,
+                diagnostics,
+            );
+            match result {
+                Ok((len, short_form)) => {
+                    if let Some(instruction) = short_form {
+                        short_forms.push(ShortForm {
+                            line: index,
+                            section: sections.current_index(),
+                            offset,
+                            instruction,
+                        });
                     }
+                    lens.push(Some(len));
+                }
+                Err(error) => {
+                    diagnostics.push(error.into());
+                    lens.push(None);
                 }
-                Line::Unknown(_) => return Err(line_count),
             }
         }
 
-        Ok(labels)
+        sections.layout();
+        symbols.relocate_labels(addresses, &sections);
+
+        (symbols, sections, lens, short_forms)
+    }
+
+    /// Lay out line, and returns its length
+    /// Instruction in short form is also returned if it is tried and shorter
+    fn layout_line(
+        &self,
+        location: &Location,
+        laid_out: &mut LaidOutLine,
+        symbols: &mut SymbolTable,
+        sections: &mut SectionTable,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<(usize, Option<String>), AsmError> {
+        let line = laid_out.line;
+        match line {
+            Line::None => Ok((0, None)),
+            Line::Label(s) => {
+                let executable = sections.current().flags().is_executable();
+                symbols
+                    .define_label(s, sections.current_index(), symbols.location(), executable)
+                    .map_err(|message| symbol_error(location, s, message))?;
+                Ok((0, None))
+            }
+            Line::AsmCommand(s) => {
+                if let Some(name) = line.section_name() {
+                    sections
+                        .switch(name)
+                        .map_err(|message| command_error(location, symbols, s, message))?;
+                    Ok((0, None))
+                } else if let Some((name, expr, kind)) = line.constant_definition() {
+                    let value = symbols
+                        .evaluate(expr)
+                        .map_err(|message| expression_error(location, symbols, expr, message))?;
+                    symbols
+                        .define_constant(name, value, kind)
+                        .map_err(|message| symbol_error(location, name, message))?;
+                    Ok((0, None))
+                } else {
+                    let Some(len) = line.command_len(symbols, sections.current()) else {
+                        let message = "invalid command : ".to_string() + s.trim();
+                        return Err(command_error(location, symbols, s, message));
+                    };
+                    if let Some(align) = line.command_align(symbols) {
+                        sections
+                            .align_to(align)
+                            .map_err(|message| command_error(location, symbols, s, message))?;
+                    }
+                    sections
+                        .grow(len)
+                        .map_err(|message| command_error(location, symbols, s, message))?;
+                    Ok((len, None))
+                }
+            }
+            Line::Instruction(s) => {
+                let (translated, substituted) = self.canonical_instruction(symbols, location, s)?;
+                let line = Line::Instruction(&substituted);
+                let previous = laid_out
+                    .instruction
+                    .take()
+                    .filter(|instruction| instruction.canonical == substituted);
+                if previous.is_none() && !line.is_valid_instruction() {
+                    return Err(AsmError::instruction(location, &translated, &substituted));
+                }
+                let section = sections.current();
+                if !section.flags().is_executable() {
+                    diagnostics.push(
+                        AsmWarning::CodeInDataSection {
+                            section: section.name().to_string(),
+                            span: Span::new(location, s),
+                        }
+                        .into(),
+                    );
+                }
+                let instruction = match previous {
+                    Some(instruction) => instruction,
+                    None => {
+                        let short_form = laid_out.short.then(|| short_form(&substituted)).flatten();
+                        let len = short_form
+                            .as_deref()
+                            .map_or(line, Line::Instruction)
+                            .machine_code_len()
+                            .map_err(|_| {
+                                operand_error(location, symbols, &translated, &substituted)
+                            })?;
+                        LaidOutInstruction {
+                            canonical: substituted,
+                            len,
+                            short_form,
+                        }
+                    }
+                };
+                let (len, short_form) = (instruction.len, instruction.short_form.clone());
+                laid_out.instruction = Some(instruction);
+                sections
+                    .grow(len)
+                    .map_err(|message| statement_error(location, s, message))?;
+                Ok((len, short_form))
+            }
+            Line::Unknown(s) => Err(unknown_statement(location, s)),
+        }
     }
 
-    pub fn assemble(&self) -> Result<Vec<u8>, String> {
-        let parser = Parser::new(self.source);
-        let mut vec = Vec::new();
+    pub fn assemble(&self) -> Result<Assembly, AsmError> {
+        let mut diagnostics = Vec::new();
+        let assembly = self.assemble_lines(&mut diagnostics);
+        match diagnostics.into_iter().find_map(Diagnostic::into_error) {
+            Some(error) => Err(error),
+            None => Ok(assembly),
+        }
+    }
 
-        let labels = match self.labels() {
-            Ok(v) => v,
-            Err(n) => return Err(format!("{}: unknown expression", n)),
-        };
+    /// Assemble source and collect all errors and warnings
+    /// Lines with errors are skipped, and the other lines are still checked
+    /// Warnings are returned with assembly if there are no errors
+    pub fn assemble_with_diagnostics(
+        &self,
+    ) -> Result<(Assembly, Vec<Diagnostic>), Vec<Diagnostic>> {
+        let mut diagnostics = Vec::new();
+        let assembly = self.assemble_lines(&mut diagnostics);
+        if diagnostics.iter().any(Diagnostic::is_error) {
+            Err(diagnostics)
+        } else {
+            Ok((assembly, diagnostics))
+        }
+    }
+
+    fn assemble_lines(&self, diagnostics: &mut Vec<Diagnostic>) -> Assembly {
+        let lines = self.preprocess(diagnostics);
+        let (mut symbols, mut sections, lens, short) = self.layout(&lines, diagnostics);
+        sections.rewind();
+
+        for ((source_line, len), short) in lines.iter().zip(lens).zip(short) {
+            // Errors of lines which can't be laid out are already reported
+            let Some(len) = len else {
+                continue;
+            };
+            let line = parse_line(source_line.text());
+            let result = self.assemble_line(
+                source_line.location(),
+                line,
+                len,
+                short,
+                &mut symbols,
+                &mut sections,
+            );
+            if let Err(error) = result {
+                diagnostics.push(error.into());
+                // Fill the line to keep addresses of the following lines
+                let _ = sections.current_mut().push_bytes(&vec![0; len]);
+            }
+        }
+        sort_diagnostics(diagnostics);
+
+        sections.into_assembly()
+    }
 
-        for line in parser {
-            match line {
-                Line::None => (),
-                Line::Label(_) => (),
-                Line::AsmCommand(_) => todo!(),
-                Line::Instruction(_) => {
-                    line.machine_code(&labels, vec.len())?.push_to(&mut vec);
+    /// Translate instruction into Intel syntax which INSTRUCTION_LIST matches with
+    /// Returns the translated instruction and its canonical form
+    fn canonical_instruction(
+        &self,
+        symbols: &SymbolTable,
+        location: &Location,
+        line: &str,
+    ) -> Result<(String, String), AsmError> {
+        let translated = self.syntax.translate_instruction(line).map_err(|message| {
+            AsmError::InvalidStatement {
+                statement: line.trim().to_string(),
+                message,
+                span: Span::new(location, line),
+            }
+        })?;
+        let substituted = symbols.substitute_constants(&translated);
+        match canonical_instruction(&substituted) {
+            Ok(canonical) => Ok((translated, canonical)),
+            Err(_) => {
+                let operands = split_operands(split_mnemonic(&translated).1);
+                let invalid = operands
+                    .into_iter()
+                    .find(|o| matches!(convert_memory_operand(o), Some(Err(_))));
+                Err(match invalid {
+                    Some(operand) => AsmError::InvalidMemoryOperand {
+                        operand: operand.to_string(),
+                        span: Span::new(location, operand),
+                    },
+                    None => AsmError::AmbiguousOperandSize {
+                        instruction: translated.trim().to_string(),
+                        span: Span::new(location, line),
+                    },
+                })
+            }
+        }
+    }
+
+    fn assemble_line(
+        &self,
+        location: &Location,
+        line: Line,
+        len: usize,
+        short: bool,
+        symbols: &mut SymbolTable,
+        sections: &mut SectionTable,
+    ) -> Result<(), AsmError> {
+        symbols.set_location(sections.current_address());
+        symbols.set_section_start(sections.current().address());
+        match line {
+            Line::None => (),
+            Line::Label(_) => (),
+            Line::AsmCommand(s) => {
+                if let Some(name) = line.section_name() {
+                    sections
+                        .switch(name)
+                        .map_err(|message| command_error(location, symbols, s, message))?;
+                } else if let Some((name, expr, _)) = line.constant_definition() {
+                    // Variables take the value at this point again
+                    let value = symbols
+                        .evaluate(expr)
+                        .map_err(|message| expression_error(location, symbols, expr, message))?;
+                    symbols.assign(name, value);
+                } else {
+                    // Length is checked before making data not to allocate unexpected size
+                    if line.command_len(symbols, sections.current()) != Some(len) {
+                        let message = "size differs from layout".to_string();
+                        return Err(command_error(location, symbols, s, message));
+                    }
+                    line.command_data(symbols, sections.current())
+                        .and_then(|data| sections.current_mut().push_bytes(&data))
+                        .map_err(|message| command_error(location, symbols, s, message))?;
                 }
-                Line::Unknown(_) => panic!("unknown error"),
             }
+            Line::Instruction(s) => {
+                let (translated, substituted) = self.canonical_instruction(symbols, location, s)?;
+                let line = Line::Instruction(&substituted);
+                if !line.is_valid_instruction() {
+                    return Err(AsmError::instruction(location, &translated, &substituted));
+                }
+                let short_form = short.then(|| short_form(&substituted)).flatten();
+                let code = short_form
+                    .as_deref()
+                    .map_or(line, Line::Instruction)
+                    .machine_code(symbols, sections.current_address())
+                    .map_err(|_| operand_error(location, symbols, &translated, &substituted))?;
+                if code.len() != len {
+                    let message = "size differs from layout".to_string();
+                    return Err(statement_error(location, s, message));
+                }
+                sections
+                    .current_mut()
+                    .push_bytes(&code)
+                    .map_err(|message| statement_error(location, s, message))?;
+            }
+            Line::Unknown(s) => return Err(unknown_statement(location, s)),
         }
+        Ok(())
+    }
+}
 
-        Ok(vec)
+/// Sort diagnostics in order of source, since passes report them in their own order
+/// Diagnostics in macros and included files are placed where they are expanded or included
+fn sort_diagnostics(diagnostics: &mut [Diagnostic]) {
+    diagnostics.sort_by_cached_key(|d| {
+        let span = d.span();
+        let mut order = span.location().source_order();
+        if let Some(last) = order.last_mut() {
+            last.1 = span.range().start;
+        }
+        order
+    });
+}
+
+/// Get instruction in short form like `{disp8} jmp label` if it is shorter
+fn short_form(instruction: &str) -> Option<String> {
+    let line = Line::Instruction(instruction);
+    if !line.is_relaxable() {
+        return None;
     }
+    let short_form = format!("{{disp8}} {}", instruction);
+    let short_len = Line::Instruction(&short_form).machine_code_len().ok()?;
+    (short_len < line.machine_code_len().ok()?).then_some(short_form)
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct Label<'a> {
-    name: &'a str,
-    offset: usize,
+fn symbol_error(location: &Location, name: &str, message: String) -> AsmError {
+    AsmError::InvalidSymbol {
+        name: name.to_string(),
+        message,
+        span: Span::new(location, name),
+    }
 }
 
-impl<'a> Label<'a> {
-    pub fn name(&self) -> &'a str {
-        self.name
+fn expression_error(
+    location: &Location,
+    symbols: &SymbolTable,
+    expression: &str,
+    message: String,
+) -> AsmError {
+    AsmError::expression(location, symbols, &[expression]).unwrap_or_else(|| {
+        AsmError::InvalidExpression {
+            expression: expression.trim().to_string(),
+            message,
+            span: Span::new(location, expression),
+        }
+    })
+}
+
+/// Error of assembler command
+/// Undefined symbols and invalid expressions in arguments are reported first
+fn command_error(
+    location: &Location,
+    symbols: &SymbolTable,
+    command: &str,
+    message: String,
+) -> AsmError {
+    let arguments = split_arguments(split_mnemonic(command).1);
+    AsmError::expression(location, symbols, &arguments).unwrap_or_else(|| {
+        AsmError::InvalidCommand {
+            command: command.trim().to_string(),
+            message,
+            span: Span::new(location, command),
+        }
+    })
+}
+
+/// Error of operands which can't be encoded
+/// statement is the instruction in source, and instruction is its canonical form
+fn operand_error(
+    location: &Location,
+    symbols: &SymbolTable,
+    statement: &str,
+    instruction: &str,
+) -> AsmError {
+    // Displacements of memory operands are before '['
+    let operands = split_operands(split_mnemonic(instruction).1);
+    let expressions: Vec<&str> = operands
+        .iter()
+        .map(|o| o.split('[').next().unwrap_or(o))
+        .collect();
+    if let Some(error) = AsmError::expression(location, symbols, &expressions) {
+        return error;
+    }
+
+    let statement_operands = split_operands(split_mnemonic(statement).1);
+    let operand = statement_operands
+        .iter()
+        .find(|o| o.parse::<Register>().is_err())
+        .copied()
+        .unwrap_or(statement);
+    AsmError::ImmediateOutOfRange {
+        operand: operand.trim().to_string(),
+        span: Span::new(location, operand),
+    }
+}
+
+fn statement_error(location: &Location, statement: &str, message: String) -> AsmError {
+    AsmError::InvalidStatement {
+        statement: statement.trim().to_string(),
+        message,
+        span: Span::new(location, statement),
+    }
+}
+
+fn unknown_statement(location: &Location, statement: &str) -> AsmError {
+    statement_error(location, statement, "unknown statement".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        error::{Renderer, Style},
+        parser::Parser,
+        symbol::SymbolKind,
+    };
+
+    #[test]
+    pub fn data_command_test() {
+        let source = "
+        start:
+            .byte 1, 0xff, -1
+            .word 0x1234
+            .dword start, end
+            .qword -2
+            .ascii \"ab\\n\", \"\\x41\"
+            .asciz \"\\\"\"
+        end:
+            ret";
+        let asm = Asm::new(source);
+        assert_eq!(
+            vec![
+                0x01, 0xff, 0xff, 0x34, 0x12, 0x00, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0xfe,
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, b'a', b'b', b'\n', b'A', b'"', 0x00,
+                0xc3,
+            ],
+            asm.assemble().unwrap().image()
+        );
+    }
+
+    #[test]
+    pub fn data_command_error_test() {
+        assert!(Asm::new(".byte 256").assemble().is_err());
+        assert!(Asm::new(".word").assemble().is_err());
+        assert!(Asm::new(".ascii \"abc").assemble().is_err());
+        assert!(Asm::new(".dword undefined").assemble().is_err());
     }
 
-    pub fn offset(&self) -> usize {
-        self.offset
+    #[test]
+    pub fn section_test() {
+        let source = "
+            .data
+        value:
+            .qword 7
+            .text
+        main:
+            ret
+            .section .rodata
+        table:
+            .dword value, main
+            .bss
+            .byte 0, 0";
+        let assembly = Asm::new(source).assemble().unwrap();
+        let names: Vec<&str> = assembly.sections().iter().map(|s| s.name()).collect();
+        assert_eq!(vec![".text", ".data", ".rodata", ".bss"], names);
+
+        let data = assembly.section(".data").unwrap();
+        assert_eq!(8, data.address());
+        let rodata = assembly.section(".rodata").unwrap();
+        assert!(!rodata.flags().is_writable());
+        assert_eq!(vec![8, 0, 0, 0, 0, 0, 0, 0], rodata.bytes());
+        let bss = assembly.section(".bss").unwrap();
+        assert_eq!(0, bss.bytes().len());
+        assert_eq!(2, bss.size());
+        assert_eq!(26, assembly.image().len());
+
+        assert!(Asm::new(".bss\n.byte 1").assemble().is_err());
+        assert!(Asm::new(".section .unknown").assemble().is_err());
+
+        // Flags and type emitted by GCC are ignored
+        let source = "
+            .section .rodata,\"a\"
+            .byte 1
+            .section .text,\"ax\",@progbits
+            ret
+            .section .data,\"aw\",@progbits,1
+            .byte 2";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(vec![0xc3], assembly.section(".text").unwrap().bytes());
+        assert_eq!(vec![1], assembly.section(".rodata").unwrap().bytes());
+        assert_eq!(vec![2], assembly.section(".data").unwrap().bytes());
+        assert!(Asm::new(".section .text, ax").assemble().is_err());
+    }
+
+    #[test]
+    pub fn padding_command_test() {
+        let source = "
+            ret
+            .p2align 3
+        aligned:
+            ret
+            .balign 4, 0xcc
+            .fill 2, 2, 0x1234
+            .zero 1
+            .skip 2, 0xff
+            .align 16,, 2
+            .data
+            .byte aligned
+            .align 4
+            .byte 1";
+        let assembly = Asm::new(source).assemble().unwrap();
+        let text = assembly.section(".text").unwrap();
+        assert_eq!(
+            vec![
+                0xc3, 0x0f, 0x1f, 0x80, 0x00, 0x00, 0x00, 0x00, 0xc3, 0xcc, 0xcc, 0xcc, 0x34, 0x12,
+                0x34, 0x12, 0x00, 0xff, 0xff,
+            ],
+            text.bytes()
+        );
+        let data = assembly.section(".data").unwrap();
+        assert_eq!(vec![8, 0, 0, 0, 1], data.bytes());
+
+        assert!(Asm::new(".align 3").assemble().is_err());
+        assert!(Asm::new(".fill 1, 9, 0").assemble().is_err());
+        let assembly = Asm::new(".fill 16, 0, 1\n.byte 1").assemble().unwrap();
+        assert_eq!(vec![1], assembly.image());
+        assert!(Asm::new(".fill 0x7fffffffffff, 0, 1").assemble().is_err());
+    }
+
+    #[test]
+    pub fn constant_command_test() {
+        let source = "
+            .equ SIZE, 16
+            .set COUNT, 2
+            .equ ALIAS, SIZE
+            mov rax ALIAS
+            .set COUNT, 3
+            add rax COUNT
+            mov SIZE[rbp]q rcx
+            ret
+            .data
+        value:
+            .byte SIZE, COUNT";
+        let asm = Asm::new(source);
+        let symbols = asm.symbols().unwrap();
+        assert_eq!(SymbolKind::Constant, symbols.get("SIZE").unwrap().kind());
+        assert_eq!(SymbolKind::DataLabel, symbols.get("value").unwrap().kind());
+
+        let assembly = asm.assemble().unwrap();
+        assert_eq!(
+            vec![
+                0x48, 0xb8, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0x83, 0xc0, 0x03,
+                0x48, 0x89, 0x4d, 0x10, 0xc3,
+            ],
+            assembly.section(".text").unwrap().bytes()
+        );
+        assert_eq!(vec![16, 3], assembly.section(".data").unwrap().bytes());
+
+        assert!(Asm::new(".equ A, 1\n.equ A, 2").assemble().is_err());
+        assert!(Asm::new(".set A, 1\n.equ A, 2").assemble().is_err());
+        assert!(Asm::new("a:\n.set a, 2").assemble().is_err());
+        assert!(Asm::new("a:\na:").assemble().is_err());
+        assert!(Asm::new(".equ rax, 1").assemble().is_err());
+    }
+
+    #[test]
+    pub fn macro_test() {
+        let source = "
+            .macro countdown reg, from=3
+            mov \\reg \\from
+        loop\\@:
+            dec \\reg
+            .endm
+            .macro twice reg
+            countdown \\reg
+            countdown \\reg, from=1
+            .endm
+            twice ecx
+            ret";
+        let symbols = Asm::new(source).symbols().unwrap();
+        assert_eq!(Some(5), symbols.value("loop1"));
+        assert_eq!(Some(12), symbols.value("loop2"));
+
+        let error = Asm::new(source.replace("dec", "bad").as_str())
+            .assemble()
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("line 11, macro body line 8, macro body line 5:"));
+        assert!(Asm::new(".macro m\nret").assemble().is_err());
+        assert!(Asm::new(".macro m\n.endm\nm 1").assemble().is_err());
+    }
+
+    #[test]
+    pub fn conditional_test() {
+        let source = "
+            .equ FAST, 1
+            .equ LEVEL, 2
+        start:
+            .ifdef FAST
+            .if LEVEL == 1
+            .byte 1
+            .elseif LEVEL >= 2
+            .byte 2
+            .ifndef start
+            .byte 3
+            .else
+            .byte 4
+            .endif
+            .else
+            .byte 5
+            .endif
+            .else
+            .byte 6
+            .endif
+            .ifdef SLOW
+            .byte 7
+            .if UNDEFINED
+            .endif
+            .endif";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(vec![2, 4], assembly.image());
+
+        let error = Asm::new(".if 1\n.if 0\n.endif")
+            .assemble()
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("line 1:"));
+        assert!(Asm::new(".else").assemble().is_err());
+        assert!(Asm::new(".if 1\n.else\n.else\n.endif").assemble().is_err());
+        assert!(Asm::new(".if UNDEFINED\n.endif").assemble().is_err());
+
+        let source = "
+        start:
+            .equ END, start + 4
+            .set HERE, $
+            .ifdef END
+            .byte 1
+            .endif
+            .ifndef HERE
+            .byte 2
+            .endif";
+        assert_eq!(vec![1], Asm::new(source).assemble().unwrap().image());
+        assert!(Asm::new("start:\n.equ END, start + 4\n.if END\n.endif")
+            .assemble()
+            .is_err());
+    }
+
+    #[test]
+    pub fn include_test() {
+        let dir = std::env::temp_dir().join(format!("asm-include-test-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("lib")).unwrap();
+        std::fs::write(dir.join("lib/defs.s"), ".equ VALUE, 7\n.include \"more.s\"").unwrap();
+        std::fs::write(dir.join("lib/more.s"), "\n.byte VALUE").unwrap();
+        std::fs::write(dir.join("lib/blob.bin"), [1, 2, 3, 4, 5]).unwrap();
+        std::fs::write(dir.join("cycle.s"), ".include \"cycle.s\"").unwrap();
+        let paths = [dir.clone(), dir.join("lib")];
+
+        let source = "
+            .include \"defs.s\"
+            .incbin \"blob.bin\", 1, 3
+            .incbin \"lib/blob.bin\", 4";
+        let assembly = Asm::new(source)
+            .with_include_paths(&paths)
+            .assemble()
+            .unwrap();
+        assert_eq!(vec![7, 2, 3, 4, 5], assembly.image());
+
+        std::fs::write(dir.join("lib/more.s"), "\n.byte VALUE\nbad").unwrap();
+        let error = Asm::new(source)
+            .with_include_paths(&paths)
+            .assemble()
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with(&format!("{} line 3:", dir.join("lib/more.s").display())));
+
+        let error = Asm::new(".include \"cycle.s\"")
+            .with_include_paths(&paths)
+            .assemble()
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("include cycle"));
+        assert!(Asm::new(".include \"none.s\"").assemble().is_err());
+        assert!(Asm::new(".incbin \"blob.bin\", 6")
+            .with_include_paths(&paths)
+            .assemble()
+            .is_err());
+
+        let absolute = format!(".incbin \"{}\"", dir.join("lib/blob.bin").display());
+        assert!(Asm::new(&absolute).assemble().is_err());
+        let assembly = Asm::new(&absolute)
+            .with_absolute_includes(true)
+            .assemble()
+            .unwrap();
+        assert_eq!(vec![1, 2, 3, 4, 5], assembly.image());
+        let assembly = Asm::new(".incbin \"lib/../lib/./blob.bin\", 4")
+            .with_include_paths(&paths)
+            .assemble()
+            .unwrap();
+        assert_eq!(vec![5], assembly.image());
+        let error = Asm::new(".incbin \"../lib/blob.bin\"")
+            .with_include_paths(&paths[1..])
+            .assemble()
+            .unwrap_err();
+        assert!(error.to_string().contains("must be inside include paths"));
+        assert!(Asm::new(".incbin \"Cargo.toml\"").assemble().is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    pub fn repetition_test() {
+        let source = "
+            .equ COUNT, 2
+            .rept COUNT
+            .irp reg, rax, rcx
+            push \\reg
+            .endr
+            .endr
+            .data
+            .irpc c, 135
+            .rept \\c
+            .byte \\c
+            .endr
+            .endr";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(
+            vec![0x50, 0x51, 0x50, 0x51],
+            assembly.section(".text").unwrap().bytes()
+        );
+        assert_eq!(
+            vec![1, 3, 3, 3, 5, 5, 5, 5, 5],
+            assembly.section(".data").unwrap().bytes()
+        );
+
+        let error = Asm::new(".rept 3\nbad\n.endr")
+            .assemble()
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("line 2:"));
+        assert!(Asm::new(".rept 2\nret").assemble().is_err());
+        assert!(Asm::new(".endr").assemble().is_err());
+        assert!(Asm::new(".rept -1\n.endr").assemble().is_err());
+    }
+
+    #[test]
+    pub fn expression_test() {
+        let source = "
+        start:
+            mov rax end-start
+            add rcx (1<<4)|3
+            mov 8*2[rbp]q rcx
+            push table+8
+            mov rax $-start+4
+        end:
+            .data
+        table:
+            .qword $, end - start, table + 8
+            .equ LEN, $ - table
+            .byte LEN, ~LEN + 1
+            .fill LEN / 8, 1, -1
+            .equ COUNT, 3
+            .if COUNT % 3 == 0 && (COUNT << 3) == 24
+            .byte 1
+            .endif";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(
+            vec![
+                0x48, 0xb8, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0x83, 0xc1, 0x13,
+                0x48, 0x89, 0x4d, 0x10, 0x68, 0x30, 0x00, 0x00, 0x00, 0x48, 0xb8, 0x1b, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00,
+            ],
+            assembly.section(".text").unwrap().bytes()
+        );
+        assert_eq!(
+            vec![
+                0x28, 0, 0, 0, 0, 0, 0, 0, 0x21, 0, 0, 0, 0, 0, 0, 0, 0x30, 0, 0, 0, 0, 0, 0, 0,
+                0x18, 0xe8, 0xff, 0xff, 0xff, 0x01,
+            ],
+            assembly.section(".data").unwrap().bytes()
+        );
+
+        assert!(Asm::new(".byte 1 / 0").assemble().is_err());
+        assert!(Asm::new(".byte (1").assemble().is_err());
+        assert!(Asm::new(".byte 1 +").assemble().is_err());
+        assert!(Asm::new("push rax+1").assemble().is_err());
+
+        // Labels in other sections and $ are addresses in image in layout too
+        let source = "
+            ret
+            .data
+        data:
+            .byte 1
+            .skip $ - 8
+            .equ HERE, $
+            .bss
+            .skip data
+            .skip $$ - HERE";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(vec![1, 0], assembly.section(".data").unwrap().bytes());
+        assert_eq!(8 + 16 - 10, assembly.section(".bss").unwrap().size());
+        assert!(Asm::new("ret\n.data\nb:\n.text\n.skip b")
+            .assemble()
+            .is_err());
+    }
+
+    #[test]
+    pub fn comment_test() {
+        let source = "
+            # comment
+        start: ; label
+            push rcx // instruction
+            /* block comment
+            ret */ pop rcx /* inline */
+            .ascii \"; # // /*\" ; string
+            bad";
+        let error = Asm::new(source).assemble().unwrap_err().to_string();
+        assert!(error.starts_with("line 8:"));
+
+        let assembly = Asm::new(&source.replace("bad", "")).assemble().unwrap();
+        assert_eq!(
+            vec![0x51, 0x59, b';', b' ', b'#', b' ', b'/', b'/', b' ', b'/', b'*'],
+            assembly.image()
+        );
+
+        // Code after an inline block comment is kept in both Asm and Parser
+        let source = "push rcx /* x */ pop rcx\nmov rax, /* x */ 1";
+        assert!(Asm::new(source).assemble().is_err());
+        let lines: Vec<Line> = Parser::new(source).collect();
+        assert!(matches!(
+            lines.as_slice(),
+            [
+                Line::Unknown("push rcx /* x */ pop rcx"),
+                Line::Unknown("mov rax, /* x */ 1")
+            ]
+        ));
+        let mut parser = Parser::new("/* x */ ret ; x\n/* x\n x */ main: // x\n\"/*\" #");
+        assert!(matches!(parser.next(), Some(Line::Instruction("ret"))));
+        assert!(matches!(parser.next(), Some(Line::None)));
+        assert!(matches!(parser.next(), Some(Line::Label("main"))));
+        assert!(matches!(parser.next(), Some(Line::Unknown("\"/*\""))));
+        assert_eq!(4, parser.line_number());
+        assert!(parser.next().is_none());
+        assert_eq!(
+            vec![0x51],
+            Asm::new("push /* x */ rcx").assemble().unwrap().image()
+        );
+    }
+
+    #[test]
+    pub fn operand_syntax_test() {
+        let source = "
+            mov rax, rdi
+            mov\trcx ,rax
+            add  rax,   1 + 2
+            mov -8[ rbp , rcx , 4 ]q,\trcx
+            mov 8[rbp]q rcx
+            push (1 + 2) * 2";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(
+            vec![
+                0x48, 0x89, 0xf8, 0x48, 0x89, 0xc1, 0x48, 0x83, 0xc0, 0x03, 0x48, 0x89, 0x4c, 0x8d,
+                0xf8, 0x48, 0x89, 0x4d, 0x08, 0x68, 0x06, 0x00, 0x00, 0x00,
+            ],
+            assembly.image()
+        );
+
+        assert!(Asm::new("mov rax,").assemble().is_err());
+        assert!(Asm::new("mov rax, rcx, rdx").assemble().is_err());
+        assert!(Asm::new("mov rax rcx rdx").assemble().is_err());
+
+        // Expressions with spaces are not split into operands
+        let source = "
+        start:
+            push 1 +2
+            push end -8
+            jmp start +2
+        end:";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(
+            vec![0x68, 0x03, 0x00, 0x00, 0x00, 0x68, 0x04, 0x00, 0x00, 0x00, 0xeb, 0xf6],
+            assembly.image()
+        );
+
+        // Signs after register and memory operands start operands in the old syntax
+        let source = "
+            mov rax -1
+            mov 8[rbp]q -1
+            shld rax rbx 4";
+        assert_eq!(
+            vec![
+                0x48, 0xb8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x48, 0xc7, 0x45, 0x08,
+                0xff, 0xff, 0xff, 0xff, 0x48, 0x0f, 0xa4, 0xd8, 0x04,
+            ],
+            Asm::new(source).assemble().unwrap().image()
+        );
+    }
+
+    #[test]
+    pub fn operand_size_prefix_test() {
+        assert_eq!(
+            vec![0x66, 0x83, 0x01, 0x01, 0x80, 0x01, 0x01],
+            Asm::new("add [rcx]w, 1\nadd [rcx]b, 1")
+                .assemble()
+                .unwrap()
+                .image()
+        );
+    }
+
+    #[test]
+    pub fn register_test() {
+        assert_eq!(
+            vec![0x53, 0x48, 0x89, 0xc3],
+            Asm::new("push rbx\nmov rbx, rax")
+                .assemble()
+                .unwrap()
+                .image()
+        );
+
+        // Registers embedded in opecode like r12 of push are extended by REX.B
+        assert_eq!(
+            vec![0x41, 0x54, 0x41, 0x5d, 0x49, 0xb8, 0x01, 0, 0, 0, 0, 0, 0, 0],
+            Asm::new("push r12\npop r13\nmov r8, 1")
+                .assemble()
+                .unwrap()
+                .image()
+        );
+        assert_eq!(
+            vec![0x41, 0x54, 0x41, 0x5d],
+            Asm::new("pushq %r12\npopq %r13")
+                .with_syntax(Syntax::Att)
+                .assemble()
+                .unwrap()
+                .image()
+        );
+    }
+
+    #[test]
+    pub fn sib_index_test() {
+        assert_eq!(
+            vec![0x48, 0x89, 0x04, 0x04],
+            Asm::new("mov [rsp,rax,1]q, rax")
+                .assemble()
+                .unwrap()
+                .image()
+        );
+        assert!(Asm::new("mov [rax,rsp,1]q, rax").assemble().is_err());
+    }
+
+    #[test]
+    pub fn rip_relative_test() {
+        let source = "
+            mov data[rip]q, rax
+            push 8[rip]q
+        data:";
+        assert_eq!(
+            vec![0x48, 0x89, 0x05, 0x06, 0x00, 0x00, 0x00, 0xff, 0x35, 0x08, 0x00, 0x00, 0x00],
+            Asm::new(source).assemble().unwrap().image()
+        );
+        assert!(Asm::new("mov [rip,rcx,1]q, rax").assemble().is_err());
+    }
+
+    #[test]
+    pub fn memory_operand_test() {
+        let source = "
+            mov qword ptr [rax + rbx*8 + 16], rcx
+            mov [rsp], rax
+            mov [r13], rax
+            mov [ rbp - 8 ], rdx
+            mov [8*2 + rbx + 4*r12 ], rdx
+            add byte ptr [rcx + 1], 1
+            add word ptr [rcx], 1
+            push [rdi]
+            mov [rip + data], rax
+            mov 8[rbp]q, rcx
+            mov [rip + data + 8], rax
+        data:";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(
+            vec![
+                0x48, 0x89, 0x4c, 0xd8, 0x10, 0x48, 0x89, 0x04, 0x24, 0x49, 0x89, 0x45, 0x00, 0x48,
+                0x89, 0x55, 0xf8, 0x4a, 0x89, 0x54, 0xa3, 0x10, 0x80, 0x41, 0x01, 0x01, 0x66, 0x83,
+                0x01, 0x01, 0xff, 0x37, 0x48, 0x89, 0x05, 0x0b, 0x00, 0x00, 0x00, 0x48, 0x89, 0x4d,
+                0x08, 0x48, 0x89, 0x05, 0x08, 0x00, 0x00, 0x00,
+            ],
+            assembly.image()
+        );
+
+        let error = Asm::new("add [rax], 1").assemble().unwrap_err().to_string();
+        assert!(error.contains("ambiguous operand size"));
+        assert!(Asm::new("mov dword ptr [rax], rcx").assemble().is_err());
+        assert!(Asm::new("mov [rax - rbx], rcx").assemble().is_err());
+        assert!(Asm::new("mov [rip + rcx], rax").assemble().is_err());
+        assert!(Asm::new("mov [rsp*2 + rax], rax").assemble().is_err());
+        assert!(Asm::new("mov tbyte ptr [rax], rax").assemble().is_err());
+
+        // Memory operands without base have disp32 and SIB base 101
+        let assembly = Asm::new("push [rcx*8 + 16]\nmov [r9*2], rax")
+            .assemble()
+            .unwrap();
+        assert_eq!(
+            vec![
+                0xff, 0x34, 0xcd, 0x10, 0x00, 0x00, 0x00, 0x4a, 0x89, 0x04, 0x4d, 0x00, 0x00, 0x00,
+                0x00,
+            ],
+            assembly.image()
+        );
+        assert!(Asm::new("push [rip*2]").assemble().is_err());
+
+        // Displacements are disp8 or disp32 whatever the operand size is
+        let source = "
+            add byte ptr [rax + 200], 1
+            add al, byte ptr [rbx + 0x100]
+            add word ptr [rcx + 0x10000], ax";
+        assert_eq!(
+            vec![
+                0x80, 0x80, 0xc8, 0x00, 0x00, 0x00, 0x01, 0x02, 0x83, 0x00, 0x01, 0x00, 0x00, 0x66,
+                0x01, 0x81, 0x00, 0x00, 0x01, 0x00,
+            ],
+            Asm::new(source).assemble().unwrap().image()
+        );
+    }
+
+    #[test]
+    pub fn cqo_test() {
+        assert_eq!(
+            vec![0x48, 0x99],
+            Asm::new("cqo").assemble().unwrap().image()
+        );
+    }
+
+    #[test]
+    pub fn att_syntax_test() {
+        let source = "
+        start:
+            movq %rdi, %rax
+            addq $(1 << 4), %rax
+            movq %rcx, 8(%rbp,%rcx,4)
+            movq %rdx, -8(%rbp)
+            addl $1, (%rax)
+            addb $1, 1(%rcx)
+            add %ecx, %eax
+            pushq (%rdi)
+            movq %rax, data(%rip)
+            call *%rax
+            call start
+            cqto
+            retq
+        data:
+            .quad . - start";
+        let assembly = Asm::new(source)
+            .with_syntax(Syntax::Att)
+            .assemble()
+            .unwrap();
+        assert_eq!(
+            vec![
+                0x48, 0x89, 0xf8, 0x48, 0x83, 0xc0, 0x10, 0x48, 0x89, 0x4c, 0x8d, 0x08, 0x48, 0x89,
+                0x55, 0xf8, 0x83, 0x00, 0x01, 0x80, 0x41, 0x01, 0x01, 0x01, 0xc8, 0xff, 0x37, 0x48,
+                0x89, 0x05, 0x0a, 0x00, 0x00, 0x00, 0xff, 0xd0, 0xe8, 0xd7, 0xff, 0xff, 0xff, 0x48,
+                0x99, 0xc3, 0x2c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ],
+            assembly.image()
+        );
+
+        let att = |source| Asm::new(source).with_syntax(Syntax::Att).assemble();
+        assert!(att("movq data, %rax\ndata:").is_err());
+        assert!(att("movq %rax, (%rax,%rcx,3)").is_err());
+        assert!(att("addw $1, (%rax)").is_ok());
+        assert!(att("add $1, (%rax)").is_err());
+
+        let source = "
+            movq %rax, 0x10(,%rcx,8)
+            pushq (,%r9,2)
+            sall %eax
+            shrq %rax
+            rolb (%rdi)
+            shlq %cl, %rax";
+        assert_eq!(
+            vec![
+                0x48, 0x89, 0x04, 0xcd, 0x10, 0x00, 0x00, 0x00, 0x42, 0xff, 0x34, 0x4d, 0x00, 0x00,
+                0x00, 0x00, 0xd1, 0xe0, 0x48, 0xd1, 0xe8, 0xd0, 0x07, 0x48, 0xd3, 0xe0,
+            ],
+            att(source).unwrap().image()
+        );
+        assert!(att("addl %rcx, %rax").is_err());
+        assert!(att("addl %ecx, %rax").is_err());
+        assert!(att("shlb %cl, %rax").is_err());
+        assert!(att("movq %rax, 0x10(,%rsp,8)").is_err());
+    }
+
+    #[test]
+    pub fn nasm_syntax_test() {
+        let source = "
+        %define COUNT 3
+        %macro save 1
+            push %1
+        %%skip:
+        %endmacro
+            bits 64
+            section .text
+        start:  mov rax, 0FFh   ; hex
+            add byte [rax], 1010b
+            mov rcx, COUNT
+            save rcx
+            mov [rel message], rax
+            add rcx, 'ab'
+        %ifdef COUNT
+            cqo
+        %endif
+            times 48-($-$$) db 90h
+            section .data
+        message db 'hi', 0Ah, `\\0`
+        words   dw 'abc'
+        len     equ $ - message
+            times 2 db 0q17
+            align 4
+            dd len
+            section .bss
+        buffer  resq 2";
+        let assembly = Asm::new(source)
+            .with_syntax(Syntax::Nasm)
+            .assemble()
+            .unwrap();
+        assert_eq!(
+            vec![
+                0x48, 0xb8, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x0a, 0x48,
+                0xb9, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x51, 0x48, 0x89, 0x05, 0x11,
+                0x00, 0x00, 0x00, 0x48, 0x81, 0xc1, 0x61, 0x62, 0x00, 0x00, 0x48, 0x99, 0x90, 0x90,
+                0x90, 0x90, 0x90, 0x90, 0x90, 0x90,
+            ],
+            assembly.section(".text").unwrap().bytes()
+        );
+        assert_eq!(
+            vec![b'h', b'i', 0x0a, 0x00, b'a', b'b', b'c', 0x00, 0x0f, 0x0f, 0, 0, 8, 0, 0, 0],
+            assembly.section(".data").unwrap().bytes()
+        );
+        assert_eq!(16, assembly.section(".bss").unwrap().size());
+
+        let nasm = |source| Asm::new(source).with_syntax(Syntax::Nasm).assemble();
+        assert!(nasm("org 100h").is_err());
+        assert!(nasm("default rel\nmov [rel message], rax\nmessage: dq 0").is_err());
+        assert!(nasm("[default rel]").is_err());
+        assert_eq!(vec![0xc3], nasm("default abs\nret").unwrap().image());
+        assert!(nasm("%unknown").is_err());
+        assert!(nasm("db").is_err());
+        assert!(nasm("mov rax, 0FFz").is_err());
+        assert!(nasm("%macro m 65\n%endmacro").is_err());
+        assert!(nasm("%macro m 1-65\n%endmacro").is_err());
+        assert!(nasm("%macro m 64\n%endmacro").is_ok());
+
+        // `//` is division and `#` doesn't start a comment in NASM
+        assert_eq!(
+            vec![0x48, 0xb8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            nasm("mov rax, 10 // 3 ; comment").unwrap().image()
+        );
+        assert!(nasm("mov rax, 10 # 3").is_err());
+
+        // Strings in %define are converted where they are used
+        assert_eq!(
+            vec![b'h', b'i', 0x00, 0xb8, b'h', b'i', 0x00, 0x00],
+            nasm("%define MSG 'hi'\ndb MSG, 0\nmov eax, MSG")
+                .unwrap()
+                .image()
+        );
+        assert_eq!(
+            vec![0xff, 0xc8, 0x02],
+            nasm("db $0FF, $0c8\ndb $-$$").unwrap().image()
+        );
+
+        // times repeats any statement after count
+        let source = "
+        %macro save 1
+            push %1
+        %endmacro
+            times 2 save rcx
+            times (1 + 1) * 2 - 3 cqo";
+        assert_eq!(vec![0x51, 0x51, 0x48, 0x99], nasm(source).unwrap().image());
+        let error = nasm("times 2 nop").unwrap_err();
+        assert!(matches!(error, AsmError::UnknownMnemonic { .. }));
+
+        // Memory operands with size and without ptr
+        let source = "
+            inc qword [rax]
+            push qword [rax]
+            not dword [rbx]
+            neg byte [rdi]
+            jmp qword [rax]
+            sete byte [rax]";
+        assert_eq!(
+            vec![
+                0x48, 0xff, 0x00, 0xff, 0x30, 0xf7, 0x13, 0xf6, 0x1f, 0xff, 0x20, 0x0f, 0x94, 0x00,
+            ],
+            nasm(source).unwrap().image()
+        );
+    }
+
+    #[test]
+    pub fn nasm_local_label_test() {
+        let source = "
+            section .text
+        first:
+            mov rcx, 3
+        .loop:  dec rcx
+            jnz .loop
+            ret
+        second:
+            mov rcx, 2
+        .loop:  dec rcx
+            jnz .loop
+            jmp first.loop
+            section .data
+            dq first.loop, .loop";
+        let assembly = Asm::new(source)
+            .with_syntax(Syntax::Nasm)
+            .assemble()
+            .unwrap();
+        assert_eq!(
+            vec![
+                0x48, 0xb9, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0xff, 0xc9, 0x75,
+                0xfb, 0xc3, 0x48, 0xb9, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0xff,
+                0xc9, 0x75, 0xfb, 0xeb, 0xe9,
+            ],
+            assembly.section(".text").unwrap().bytes()
+        );
+        assert_eq!(
+            vec![10, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0],
+            assembly.section(".data").unwrap().bytes()
+        );
+    }
+
+    #[test]
+    pub fn local_label_test() {
+        let source = "
+            .macro spin reg
+        1:
+            dec \\reg
+            call 1b
+            .endm
+        first_func:
+            call .Lexit
+        .Lexit:
+            ret
+        second.func:
+            call .Lexit
+            spin rcx
+            spin rdx
+            call 1f
+        .Lexit:
+        1:
+            ret
+            .equ .Lsize, . - second.func
+            .byte .Lsize";
+        let asm = Asm::new(source);
+        let symbols = asm.symbols().unwrap();
+        assert_eq!(Some(5), symbols.value("first_func.Lexit"));
+        assert_eq!(Some(32), symbols.value("second.func.Lexit"));
+        assert_eq!(
+            vec![
+                0xe8, 0x00, 0x00, 0x00, 0x00, 0xc3, 0xe8, 0x15, 0x00, 0x00, 0x00, 0x48, 0xff, 0xc9,
+                0xe8, 0xf8, 0xff, 0xff, 0xff, 0x48, 0xff, 0xca, 0xe8, 0xf8, 0xff, 0xff, 0xff, 0xe8,
+                0x00, 0x00, 0x00, 0x00, 0xc3, 0x1b,
+            ],
+            asm.assemble().unwrap().image()
+        );
+
+        let error = Asm::new("call 1f\n1b:").assemble().unwrap_err().to_string();
+        assert!(error.contains("undefined label : 1f"));
+        assert!(Asm::new("call 1b\n1:").assemble().is_err());
+        assert!(Asm::new("f:\n.La:\n.La:").assemble().is_err());
+        assert!(Asm::new("f:\n.La:\ng:\n.La:").assemble().is_ok());
+    }
+
+    #[test]
+    pub fn statement_test() {
+        let source = "
+            .macro save reg @ push \\reg @ .endm
+        start: save rax @ save rcx
+        1: .Lend: pop rcx @ pop rax
+            ret @ .ascii \"@ :\"";
+        let asm = Asm::new(source);
+        assert_eq!(Some(2), asm.symbols().unwrap().value("start.Lend"));
+        assert_eq!(
+            vec![0x50, 0x51, 0x59, 0x58, 0xc3, b'@', b' ', b':'],
+            asm.assemble().unwrap().image()
+        );
+
+        let error = Asm::new("\nstart: ret @ bad")
+            .assemble()
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("line 2:"));
+
+        // @ next to a mnemonic is reported instead of making a bogus statement
+        for source in ["ret@ret", "loop: dec rcx@jnz loop", "ret @ret"] {
+            let error = Asm::new(source).assemble().unwrap_err().to_string();
+            assert!(error.contains("@ next to a name doesn't separate statements"));
+        }
+
+        // @ in operands like @progbits and foo@PLT doesn't separate statements
+        let parser = Parser::new(".section .text,\"ax\",@progbits\ncall foo@PLT @ ret");
+        let lines: Vec<Line> = parser.collect();
+        assert!(matches!(
+            lines.as_slice(),
+            [
+                Line::AsmCommand(".section .text,\"ax\",@progbits"),
+                Line::Instruction("call foo@PLT"),
+                Line::Instruction("ret")
+            ]
+        ));
+    }
+
+    #[test]
+    pub fn error_test() {
+        let error = Asm::new("ret\n  bad rax").assemble().unwrap_err();
+        assert!(matches!(error, AsmError::UnknownMnemonic { .. }));
+        assert_eq!(
+            (2, 3, 6..9),
+            (
+                error.span().line(),
+                error.span().column(),
+                error.span().range()
+            )
+        );
+
+        let error = Asm::new("push al").assemble().unwrap_err();
+        assert!(matches!(error, AsmError::NoMatchingForm { .. }));
+        assert_eq!(0..7, error.span().range());
+
+        let error = Asm::new("mov rax, end + 1").assemble().unwrap_err();
+        assert!(matches!(error, AsmError::UndefinedLabel { ref label, .. } if label == "end"));
+        assert_eq!(9..12, error.span().range());
+
+        let error = Asm::new("mov [rax - rbx], rcx").assemble().unwrap_err();
+        assert!(matches!(error, AsmError::InvalidMemoryOperand { .. }));
+        assert_eq!(4..15, error.span().range());
+
+        let error = Asm::new("add [rax], 1").assemble().unwrap_err();
+        assert!(matches!(error, AsmError::AmbiguousOperandSize { .. }));
+        let error = Asm::new("mov [rax], 1").assemble().unwrap_err();
+        assert!(matches!(error, AsmError::AmbiguousOperandSize { .. }));
+        assert_eq!(
+            vec![0x48, 0xc7, 0x00, 0x01, 0x00, 0x00, 0x00],
+            Asm::new("mov qword ptr [rax], 1")
+                .assemble()
+                .unwrap()
+                .image()
+        );
+
+        let error = Asm::new("add cl, 256").assemble();
+        assert!(matches!(error, Err(AsmError::ImmediateOutOfRange { .. })));
+
+        // Immediates sign-extended to 64 bits must be in signed range
+        for source in [
+            "add rax, 0xffffffff",
+            "cmp rax, 0x80000000",
+            "test rax, 0xffffffff",
+            "mov qword ptr [rax], 0x80000000",
+            "push 0xffffffff",
+        ] {
+            let error = Asm::new(source).assemble();
+            assert!(matches!(error, Err(AsmError::ImmediateOutOfRange { .. })));
+        }
+        assert_eq!(
+            vec![0x05, 0xff, 0xff, 0xff, 0xff, 0x48, 0x83, 0xc0, 0xff],
+            Asm::new("add eax, 0xffffffff\nadd rax, -1")
+                .assemble()
+                .unwrap()
+                .image()
+        );
+
+        let error = Asm::new(".byte 1\n.byte 256").assemble().unwrap_err();
+        assert!(matches!(error, AsmError::InvalidCommand { .. }));
+        assert_eq!("line 2: invalid command : .byte 256", error.to_string());
+    }
+
+    #[test]
+    pub fn diagnostics_test() {
+        let source = "
+            .macro m
+            .endm
+            .macro m
+            .endm
+        start:
+            bad rax
+            .if UNDEFINED
+            .byte 1
+            .else
+            .byte 2
+            .endif
+            push undefined
+            .byte 256
+            call start
+            .data
+            ret";
+        let diagnostics = Asm::new(source).assemble_with_diagnostics().unwrap_err();
+        let lines: Vec<(usize, bool)> = diagnostics
+            .iter()
+            .map(|d| (d.span().line(), d.is_error()))
+            .collect();
+        assert_eq!(
+            vec![
+                (4, false),
+                (7, true),
+                (8, true),
+                (13, true),
+                (14, true),
+                (17, false)
+            ],
+            lines
+        );
+        assert!(diagnostics.windows(2).all(|d| {
+            let (a, b) = (d[0].span(), d[1].span());
+            (a.line(), a.range().start) <= (b.line(), b.range().start)
+        }));
+
+        let (assembly, warnings) = Asm::new(".data\nret").assemble_with_diagnostics().unwrap();
+        assert_eq!(vec![0xc3], assembly.image());
+        assert!(matches!(
+            warnings.as_slice(),
+            [Diagnostic::Warning(AsmWarning::CodeInDataSection { .. })]
+        ));
+
+        let error = Asm::new("bad\nbad2").assemble().unwrap_err();
+        assert_eq!(1, error.span().line());
+
+        let dir = std::env::temp_dir().join(format!("asm-diagnostics-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.s"), "\n\n\n\nbad_included").unwrap();
+        let paths = [dir.clone()];
+        let source = "
+            .include \"a.s\"
+            bad_main
+            .macro m
+            .byte 1
+            .byte 2
+            .byte 3
+            .byte 4
+            .byte 256
+            .endm
+            bad_before_expansion
+            m";
+        let diagnostics = Asm::new(source)
+            .with_include_paths(&paths)
+            .assemble_with_diagnostics()
+            .unwrap_err();
+        let lines: Vec<(usize, Option<usize>)> = diagnostics
+            .iter()
+            .map(|d| {
+                let location = d.span().location();
+                let outer = location.inclusion().or(location.expansion());
+                (d.span().line(), outer.map(|l| l.line()))
+            })
+            .collect();
+        assert_eq!(
+            vec![(5, Some(2)), (3, None), (11, None), (9, Some(12))],
+            lines
+        );
+        let error = Asm::new(source)
+            .with_include_paths(&paths)
+            .assemble()
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&format!("{} line 5:", dir.join("a.s").display())));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    pub fn render_test() {
+        let diagnostics = Asm::new("push al\nmov rxa, 1\n.data\n\tret")
+            .assemble_with_diagnostics()
+            .unwrap_err();
+        let renderer = Renderer::new();
+        assert_eq!(
+            "error: no matching instruction form : push al
+ --> line 1, column 1
+  |
+1 | push al
+  | ^^^^^^^
+  = note: push accepts r64 | rm64 | imm32",
+            renderer.render(&diagnostics[0])
+        );
+        assert!(renderer
+            .render(&diagnostics[1])
+            .contains("= help: `rxa` isn't a register, did you mean `rax`?"));
+        assert!(renderer
+            .render(&diagnostics[2])
+            .ends_with("4 | \tret\n  | \t^^^"));
+
+        let error = Asm::new("call strat\nstart:").assemble().unwrap_err();
+        assert!(renderer
+            .render_error(&error)
+            .ends_with("= help: did you mean `start`?"));
+        let colored = renderer.with_style(Style::Ansi).render_error(&error);
+        assert!(colored.starts_with("\x1b[1;31merror\x1b[0m"));
+    }
+
+    #[test]
+    pub fn jump_test() {
+        let source = "
+        start:
+            jmp start
+            je end
+            jz end
+            jne start
+            jnae start
+            jb start
+            loop start
+            jrcxz end
+            jmp rax
+            jmp qword ptr [rax]
+        end:
+            ret";
+        let assembly = Asm::new(source).assemble().unwrap();
+        assert_eq!(
+            vec![
+                0xeb, 0xfe, 0x74, 0x10, 0x74, 0x0e, 0x75, 0xf8, 0x72, 0xf6, 0x72, 0xf4, 0xe2, 0xf2,
+                0xe3, 0x04, 0xff, 0xe0, 0xff, 0x20, 0xc3,
+            ],
+            assembly.image()
+        );
+
+        // Aliases of condition codes are the same instructions
+        let image = |source: &str| Asm::new(source).assemble().unwrap().image().to_vec();
+        for (lhs, rhs) in [("jc", "jb"), ("jna", "jbe"), ("jpe", "jp"), ("jnge", "jl")] {
+            let source = |mnemonic| format!("start:\n{} start", mnemonic);
+            assert_eq!(image(&source(lhs)), image(&source(rhs)));
+        }
+        assert_eq!(vec![0xe1, 0xfe], image("start: loope start"));
+        assert_eq!(vec![0xe0, 0xfe], image("start: loopnz start"));
+
+        // loop and jrcxz have only rel8 forms
+        let far = "start:\n.skip 200\nloop start";
+        assert!(Asm::new(far).assemble().is_err());
+
+        let att = Asm::new("jmp *%rax\njmp *(%rax)")
+            .with_syntax(Syntax::Att)
+            .assemble()
+            .unwrap();
+        assert_eq!(vec![0xff, 0xe0, 0xff, 0x20], att.image());
+    }
+
+    #[test]
+    pub fn relaxation_test() {
+        let image = |source: &str| Asm::new(source).assemble().unwrap().image().to_vec();
+        assert_eq!(&[0xeb, 0x7f], &image("jmp end\n.skip 127\nend:")[..2]);
+        assert_eq!(
+            &[0xe9, 0x80, 0x00, 0x00, 0x00],
+            &image("jmp end\n.skip 128\nend:")[..5]
+        );
+        assert_eq!(&[0xeb, 0x80], &image("start:\n.skip 126\njmp start")[126..]);
+        assert_eq!(
+            &[0xe9, 0x7c, 0xff, 0xff, 0xff],
+            &image("start:\n.skip 127\njmp start")[127..]
+        );
+
+        // Growing jne pushes target of jmp out of range
+        let source = "
+            jmp target
+            .skip 122
+            jne far
+        target:
+            .skip 200
+        far:";
+        let assembly = image(source);
+        assert_eq!(&[0xe9, 0x80, 0x00, 0x00, 0x00], &assembly[..5]);
+        assert_eq!(&[0x0f, 0x85, 0xc8, 0x00, 0x00, 0x00], &assembly[127..133]);
+        assert_eq!(
+            Some(133),
+            Asm::new(source).symbols().unwrap().value("target")
+        );
+
+        // Displacements which refer labels are also relaxed except rip relative ones
+        assert_eq!(
+            vec![0x48, 0x89, 0x43, 0x05, 0xc3],
+            image("mov [rbx + field], rax\nret\nfield:")
+        );
+        assert_eq!(
+            &[0x48, 0x89, 0x83, 0xd0, 0x00, 0x00, 0x00],
+            &image("mov [rbx + field], rax\n.skip 201\nfield:")[..7]
+        );
+        assert_eq!(
+            vec![0x48, 0x89, 0x05, 0x00, 0x00, 0x00, 0x00],
+            image("mov [rip + field], rax\nfield:")
+        );
+    }
+
+    #[test]
+    pub fn condition_code_test() {
+        let source = "
+            sete al
+            setne byte ptr [rdi]
+            setg sil
+            setb dl
+            setz r8l
+            cmove eax, ecx
+            cmovne rax, qword ptr [rbx + 8]
+            cmovb ax, cx
+            cmovl r8w, r9w";
+        assert_eq!(
+            vec![
+                0x0f, 0x94, 0xc0, 0x0f, 0x95, 0x07, 0x40, 0x0f, 0x9f, 0xc6, 0x0f, 0x92, 0xc2, 0x41,
+                0x0f, 0x94, 0xc0, 0x0f, 0x44, 0xc1, 0x48, 0x0f, 0x45, 0x43, 0x08, 0x66, 0x0f, 0x42,
+                0xc1, 0x66, 0x45, 0x0f, 0x4c, 0xc1,
+            ],
+            Asm::new(source).assemble().unwrap().image()
+        );
+
+        // Aliases of condition codes are the same instructions
+        let image = |source: &str| Asm::new(source).assemble().unwrap().image().to_vec();
+        for (lhs, rhs) in [
+            ("c", "b"),
+            ("nae", "b"),
+            ("z", "e"),
+            ("nle", "g"),
+            ("po", "np"),
+        ] {
+            let set = |cc| format!("set{} cl", cc);
+            let cmov = |cc| format!("cmov{} rcx, rdx", cc);
+            assert_eq!(image(&set(lhs)), image(&set(rhs)));
+            assert_eq!(image(&cmov(lhs)), image(&cmov(rhs)));
+        }
+        assert!(Asm::new("sete ax").assemble().is_err());
+        assert!(Asm::new("cmove al, cl").assemble().is_err());
+        assert!(Asm::new("cmove rax, 1").assemble().is_err());
+
+        let att = Asm::new("setae %al\ncmovneq %rcx, %rax\ncmovl (%rdi), %edx")
+            .with_syntax(Syntax::Att)
+            .assemble()
+            .unwrap();
+        assert_eq!(
+            vec![0x0f, 0x93, 0xc0, 0x48, 0x0f, 0x45, 0xc1, 0x0f, 0x4c, 0x17],
+            att.image()
+        );
+    }
+
+    #[test]
+    pub fn arithmetic_test() {
+        let source = "
+            xor eax, eax
+            sub ax, 0x1234
+            sbb al, 1
+            or qword ptr [rdi + 8], rax
+            or ecx, dword ptr [rsi]
+            xor r8w, r9w
+            test al, 1
+            test rax, rax
+            test byte ptr [rdi], 4
+            not rcx
+            neg dword ptr [rbx]
+            inc byte ptr [rax]
+            inc r9";
+        assert_eq!(
+            vec![
+                0x31, 0xc0, 0x66, 0x2d, 0x34, 0x12, 0x1c, 0x01, 0x48, 0x09, 0x47, 0x08, 0x0b, 0x0e,
+                0x66, 0x45, 0x31, 0xc8, 0xa8, 0x01, 0x48, 0x85, 0xc0, 0xf6, 0x07, 0x04, 0x48, 0xf7,
+                0xd1, 0xf7, 0x1b, 0xfe, 0x00, 0x49, 0xff, 0xc1,
+            ],
+            Asm::new(source).assemble().unwrap().image()
+        );
+        assert!(Asm::new("test rax, 1 << 32").assemble().is_err());
+        assert!(Asm::new("not rax, rcx").assemble().is_err());
+        assert!(Asm::new("inc [rax]").assemble().is_err());
+
+        let source = "
+            sub rcx, 5
+            add word ptr [rax + 200], 1
+            sbb edx, -1
+            or rax, 127
+            xor dword ptr [rdi], -128
+            cmp rcx, 128
+            and eax, -129";
+        assert_eq!(
+            vec![
+                0x48, 0x83, 0xe9, 0x05, 0x66, 0x83, 0x80, 0xc8, 0x00, 0x00, 0x00, 0x01, 0x83, 0xda,
+                0xff, 0x48, 0x83, 0xc8, 0x7f, 0x83, 0x37, 0x80, 0x48, 0x81, 0xf9, 0x80, 0x00, 0x00,
+                0x00, 0x25, 0x7f, 0xff, 0xff, 0xff,
+            ],
+            Asm::new(source).assemble().unwrap().image()
+        );
+
+        let att = Asm::new("xorl %eax, %eax\nsubq $8, %rsp\ntestq $1, (%rdi)\nincq %rax")
+            .with_syntax(Syntax::Att)
+            .assemble()
+            .unwrap();
+        assert_eq!(
+            vec![
+                0x31, 0xc0, 0x48, 0x83, 0xec, 0x08, 0x48, 0xf7, 0x07, 0x01, 0x00, 0x00, 0x00, 0x48,
+                0xff, 0xc0,
+            ],
+            att.image()
+        );
+    }
+
+    #[test]
+    pub fn shift_test() {
+        let source = "
+            shl rax, 1
+            sal rax, 1
+            shr ecx, cl
+            sar byte ptr [rdi], 3
+            rol ax, 4
+            rcl r8l, 1
+            ror qword ptr [rsi + 8], cl
+            shld rax, rbx, 4
+            shrd eax, edx, cl";
+        assert_eq!(
+            vec![
+                0x48, 0xd1, 0xe0, 0x48, 0xd1, 0xe0, 0xd3, 0xe9, 0xc0, 0x3f, 0x03, 0x66, 0xc1, 0xc0,
+                0x04, 0x41, 0xd0, 0xd0, 0x48, 0xd3, 0x4e, 0x08, 0x48, 0x0f, 0xa4, 0xd8, 0x04, 0x0f,
+                0xad, 0xd0,
+            ],
+            Asm::new(source).assemble().unwrap().image()
+        );
+        assert!(Asm::new("shl rax, dl").assemble().is_err());
+        assert!(Asm::new("shl rax, 256").assemble().is_err());
+        assert!(Asm::new("shld rax, rbx").assemble().is_err());
+
+        let att = Asm::new("shlq $2, %rax\nshrl %cl, %edx\nshldq $3, %rbx, %rax")
+            .with_syntax(Syntax::Att)
+            .assemble()
+            .unwrap();
+        assert_eq!(
+            vec![0x48, 0xc1, 0xe0, 0x02, 0xd3, 0xea, 0x48, 0x0f, 0xa4, 0xd8, 0x03],
+            att.image()
+        );
+    }
+
+    #[test]
+    pub fn never_panic_test() {
+        let error = |source: &str| {
+            let diagnostics = Asm::new(source).assemble_with_diagnostics().unwrap_err();
+            diagnostics[0].message()
+        };
+        let deep = format!(".byte {}1{}", "(".repeat(1000), ")".repeat(1000));
+        assert!(error(&deep).starts_with("expression too long"));
+        let long = format!(".byte 1{}", "+1".repeat(1000));
+        assert!(error(&long).starts_with("expression too long"));
+        assert_eq!("image exceeds 268435456 bytes", error(".skip 1 << 40"));
+        assert_eq!(
+            "image exceeds 268435456 bytes",
+            error(".align 1 << 62, 0, 0\n.data\n.align 1 << 62, 0, 0")
+        );
+        assert_eq!("define expansion too long", error(".define A A A\nA"));
+        assert_eq!(
+            "macro expansion too long",
+            error(".macro m a\nm \\a\\a\n.endm\nm x")
+        );
+        let recursion = Asm::new(".macro m\nm\nm\n.endm\nm")
+            .assemble_with_diagnostics()
+            .unwrap_err();
+        assert_eq!(1, recursion.len());
+        assert_eq!("macro expansion too deep", recursion[0].message());
+
+        for source in [
+            "push rip",
+            "mov rip, 1",
+            "mov [rip * 2], rax",
+            "mov [rax + rip], rax",
+            "mov [rsp * 2], rax",
+            "mov rax, 170141183460469231731687303715884105728",
+            "a:\ncall a + 170141183460469231731687303715884105727",
+            "x: .qword x - (-170141183460469231731687303715884105727 - 1)",
+            ".fill 0x7fffffffffff, 8, 1",
+            ".include \"missing.s\"",
+        ] {
+            assert!(Asm::new(source).assemble_with_diagnostics().is_err());
+        }
+        for source in ["%macro m 9223372036854775808", "%macro m 100000000"] {
+            let nasm = Asm::new(source).with_syntax(Syntax::Nasm);
+            assert!(nasm.assemble_with_diagnostics().is_err());
+        }
+
+        // Prefixes of source are assembled or rejected without panic
+        // They are sampled to keep the test fast
+        let source = "start:\n.macro m a\nmov qword ptr [rax + rbx*8 + 16], \\a\n.endm
+m rcx\n.rept 2\npush [rip + data]\n.endr\n1: call 1b\n.data\ndata: .byte (1 << 4) | 2";
+        for (i, _) in source.char_indices().step_by(7) {
+            for syntax in [Syntax::Intel, Syntax::Att, Syntax::Nasm] {
+                let _ = Asm::new(&source[..i])
+                    .with_syntax(syntax)
+                    .assemble_with_diagnostics();
+            }
+        }
     }
 }
@@ -0,0 +1,463 @@
+use crate::{
+    expression::Node,
+    functions::{is_keyword_char, is_quote, split_mnemonic, split_operands},
+    instruction::{OperandType, INSTRUCTION_LIST},
+    preprocessor::Location,
+    register::{Register, REGISTERS},
+    symbol::SymbolTable,
+};
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::Range,
+    path::Path,
+};
+use util::functions::edit_distance;
+
+/// Renderer of diagnostics with source snippets
+mod render;
+
+pub use render::{Renderer, Style};
+
+/// Position of error in source
+/// # Example
+/// ```
+/// use asm::Asm;
+/// let error = Asm::new("ret\n  push undefined").assemble().unwrap_err();
+/// let span = error.span();
+///
+/// assert_eq!(2, span.line());
+/// assert_eq!(8, span.column());
+/// assert_eq!(11..20, span.range());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    location: Box<Location>,
+    column: usize,
+    range: Range<usize>,
+}
+
+impl Span {
+    /// Construct Span of text in the source line at location
+    /// Span covers the whole line if text isn't found in it
+    pub fn new(location: &Location, text: &str) -> Self {
+        let source = location.source();
+        let text = text.trim();
+        let start = match find_word(source, text) {
+            Some(start) => start,
+            None => {
+                let trimmed = source.trim();
+                let start = source.find(trimmed).unwrap_or(0);
+                return Span::with_range(location, start..start + trimmed.len());
+            }
+        };
+        Span::with_range(location, start..start + text.len())
+    }
+
+    fn with_range(location: &Location, range: Range<usize>) -> Self {
+        let column = location.source()[..range.start].chars().count() + 1;
+        let offset = location.offset();
+        Span {
+            location: Box::new(location.clone()),
+            column,
+            range: offset + range.start..offset + range.end,
+        }
+    }
+
+    /// Get location of line
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// Get included file which contains error
+    /// This is None for source given to Asm
+    pub fn file(&self) -> Option<&Path> {
+        self.location.file()
+    }
+
+    /// Get line number starting from 1
+    pub fn line(&self) -> usize {
+        self.location.line()
+    }
+
+    /// Get column number starting from 1
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Get byte range in file
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+/// Error of assembly
+#[derive(Clone, Debug, PartialEq)]
+pub enum AsmError {
+    /// Mnemonic which isn't in INSTRUCTION_LIST
+    UnknownMnemonic { mnemonic: String, span: Span },
+    /// Instruction whose operands don't match with any form of the mnemonic
+    NoMatchingForm { instruction: String, span: Span },
+    /// Size of memory operand can't be inferred from the other operands
+    AmbiguousOperandSize { instruction: String, span: Span },
+    /// Memory operand which can't be encoded
+    InvalidMemoryOperand { operand: String, span: Span },
+    /// Immediate or displacement which doesn't fit in its field
+    ImmediateOutOfRange { operand: String, span: Span },
+    /// Label or symbol which isn't defined
+    /// suggestion is the defined symbol or register which has the nearest name
+    UndefinedLabel {
+        label: String,
+        suggestion: Option<String>,
+        span: Span,
+    },
+    /// Symbol which is redefined or has an invalid name
+    InvalidSymbol {
+        name: String,
+        message: String,
+        span: Span,
+    },
+    /// Expression which can't be parsed or evaluated
+    InvalidExpression {
+        expression: String,
+        message: String,
+        span: Span,
+    },
+    /// Assembler command with invalid arguments
+    InvalidCommand {
+        command: String,
+        message: String,
+        span: Span,
+    },
+    /// Statement which is neither label, command nor instruction
+    InvalidStatement {
+        statement: String,
+        message: String,
+        span: Span,
+    },
+    /// Error of preprocessor and syntax front ends like macros, conditionals and includes
+    Preprocessor { message: String, span: Span },
+}
+
+impl AsmError {
+    /// Get span of source which causes error
+    pub fn span(&self) -> &Span {
+        match self {
+            AsmError::UnknownMnemonic { span, .. }
+            | AsmError::NoMatchingForm { span, .. }
+            | AsmError::AmbiguousOperandSize { span, .. }
+            | AsmError::InvalidMemoryOperand { span, .. }
+            | AsmError::ImmediateOutOfRange { span, .. }
+            | AsmError::UndefinedLabel { span, .. }
+            | AsmError::InvalidSymbol { span, .. }
+            | AsmError::InvalidExpression { span, .. }
+            | AsmError::InvalidCommand { span, .. }
+            | AsmError::InvalidStatement { span, .. }
+            | AsmError::Preprocessor { span, .. } => span,
+        }
+    }
+
+    /// Get message without location
+    pub fn message(&self) -> String {
+        match self {
+            AsmError::UnknownMnemonic { mnemonic, .. } => {
+                "unknown mnemonic : ".to_string() + mnemonic
+            }
+            AsmError::NoMatchingForm { instruction, .. } => {
+                "no matching instruction form : ".to_string() + instruction
+            }
+            AsmError::AmbiguousOperandSize { instruction, .. } => {
+                "ambiguous operand size : ".to_string() + instruction
+            }
+            AsmError::InvalidMemoryOperand { operand, .. } => {
+                "invalid memory operand : ".to_string() + operand
+            }
+            AsmError::ImmediateOutOfRange { operand, .. } => {
+                "operand out of range : ".to_string() + operand
+            }
+            AsmError::UndefinedLabel { label, .. } => "undefined label : ".to_string() + label,
+            AsmError::InvalidStatement {
+                statement, message, ..
+            } => format!("{} : {}", message, statement),
+            AsmError::InvalidSymbol { message, .. }
+            | AsmError::InvalidExpression { message, .. }
+            | AsmError::InvalidCommand { message, .. }
+            | AsmError::Preprocessor { message, .. } => message.clone(),
+        }
+    }
+
+    /// Construct error of preprocessor at location
+    pub(crate) fn preprocessor(location: &Location, message: &str) -> Self {
+        AsmError::Preprocessor {
+            message: message.to_string(),
+            span: Span::new(location, ""),
+        }
+    }
+
+    /// Construct error of instruction which doesn't match with any form
+    /// statement is the instruction in source, and instruction is its canonical form
+    pub(crate) fn instruction(location: &Location, statement: &str, instruction: &str) -> Self {
+        let (mnemonic, operands) = split_mnemonic(instruction);
+        if !INSTRUCTION_LIST.iter().any(|i| i.mnemonic() == mnemonic) {
+            return AsmError::UnknownMnemonic {
+                mnemonic: mnemonic.to_string(),
+                span: Span::new(location, split_mnemonic(statement).0),
+            };
+        }
+
+        let statement_operands = split_operands(split_mnemonic(statement).1);
+        for (i, operand) in split_operands(operands).into_iter().enumerate() {
+            let rm_types = [
+                OperandType::Rm8,
+                OperandType::Rm16,
+                OperandType::Rm32,
+                OperandType::Rm64,
+            ];
+            if operand.contains('[') && !rm_types.iter().any(|t| t.match_with(operand)) {
+                let operand = statement_operands.get(i).copied().unwrap_or(operand);
+                return AsmError::InvalidMemoryOperand {
+                    operand: operand.to_string(),
+                    span: Span::new(location, operand),
+                };
+            }
+        }
+
+        if let Some(i) = out_of_range_operand(mnemonic, &split_operands(operands)) {
+            let operand = statement_operands.get(i).copied().unwrap_or("");
+            return AsmError::ImmediateOutOfRange {
+                operand: operand.trim().to_string(),
+                span: Span::new(location, operand),
+            };
+        }
+
+        AsmError::NoMatchingForm {
+            instruction: statement.trim().to_string(),
+            span: Span::new(location, statement),
+        }
+    }
+
+    /// Find undefined symbol or invalid expression in expressions
+    /// String literals and registers are skipped
+    pub(crate) fn expression(
+        location: &Location,
+        symbols: &SymbolTable,
+        expressions: &[&str],
+    ) -> Option<Self> {
+        for expression in expressions {
+            let expression = expression.trim();
+            if expression.is_empty()
+                || expression.starts_with(is_quote)
+                || expression.parse::<Register>().is_ok()
+            {
+                continue;
+            }
+            let invalid = |message: String| AsmError::InvalidExpression {
+                expression: expression.to_string(),
+                message,
+                span: Span::new(location, expression),
+            };
+
+            let node = match Node::parse(expression) {
+                Ok(node) => node,
+                Err(message) => return Some(invalid(message)),
+            };
+            let symbols_in_expression = node.symbols();
+            if symbols_in_expression
+                .iter()
+                .any(|s| s.parse::<Register>().is_ok())
+            {
+                continue;
+            }
+            let undefined = symbols_in_expression
+                .iter()
+                .find(|s| !s.starts_with('$') && symbols.value(s).is_none());
+            if let Some(label) = undefined {
+                let names = symbols.iter().map(|(name, _)| name);
+                let registers = REGISTERS.iter().map(|(name, _)| *name);
+                return Some(AsmError::UndefinedLabel {
+                    label: label.to_string(),
+                    suggestion: nearest(label, names.chain(registers)).map(str::to_string),
+                    span: Span::new(location, label),
+                });
+            }
+            if let Err(message) = symbols.evaluate(expression) {
+                return Some(invalid(message));
+            }
+        }
+        None
+    }
+}
+
+/// Warning of assembly
+/// Warnings don't stop assembly
+#[derive(Clone, Debug, PartialEq)]
+pub enum AsmWarning {
+    /// Macro which replaces macro with the same name
+    MacroRedefined { name: String, span: Span },
+    /// Instruction in section which isn't executable
+    CodeInDataSection { section: String, span: Span },
+}
+
+impl AsmWarning {
+    /// Get span of source which causes warning
+    pub fn span(&self) -> &Span {
+        match self {
+            AsmWarning::MacroRedefined { span, .. }
+            | AsmWarning::CodeInDataSection { span, .. } => span,
+        }
+    }
+
+    /// Get message without location
+    pub fn message(&self) -> String {
+        match self {
+            AsmWarning::MacroRedefined { name, .. } => "macro redefined : ".to_string() + name,
+            AsmWarning::CodeInDataSection { section, .. } => {
+                "instruction in non-executable section : ".to_string() + section
+            }
+        }
+    }
+}
+
+impl Display for AsmWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.span().location(), self.message())
+    }
+}
+
+/// Error or warning of assembly
+/// # Example
+/// ```
+/// use asm::Asm;
+/// let diagnostics = Asm::new("bad\npush undefined\nret").assemble_with_diagnostics().unwrap_err();
+/// let lines: Vec<usize> = diagnostics.iter().map(|d| d.span().line()).collect();
+///
+/// assert_eq!(vec![1, 2], lines);
+/// assert_eq!("line 1: error: unknown mnemonic : bad", diagnostics[0].to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Diagnostic {
+    Error(AsmError),
+    Warning(AsmWarning),
+}
+
+impl Diagnostic {
+    /// If this is an error
+    pub fn is_error(&self) -> bool {
+        matches!(self, Diagnostic::Error(_))
+    }
+
+    /// Get span of source
+    pub fn span(&self) -> &Span {
+        match self {
+            Diagnostic::Error(error) => error.span(),
+            Diagnostic::Warning(warning) => warning.span(),
+        }
+    }
+
+    /// Get message without location
+    pub fn message(&self) -> String {
+        match self {
+            Diagnostic::Error(error) => error.message(),
+            Diagnostic::Warning(warning) => warning.message(),
+        }
+    }
+
+    /// Convert into error
+    /// Returns None for warnings
+    pub fn into_error(self) -> Option<AsmError> {
+        match self {
+            Diagnostic::Error(error) => Some(error),
+            Diagnostic::Warning(_) => None,
+        }
+    }
+}
+
+impl From<AsmError> for Diagnostic {
+    fn from(error: AsmError) -> Self {
+        Diagnostic::Error(error)
+    }
+}
+
+impl From<AsmWarning> for Diagnostic {
+    fn from(warning: AsmWarning) -> Self {
+        Diagnostic::Warning(warning)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let severity = if self.is_error() { "error" } else { "warning" };
+        write!(
+            f,
+            "{}: {}: {}",
+            self.span().location(),
+            severity,
+            self.message()
+        )
+    }
+}
+
+/// Find constant operand which would match with a form of mnemonic if it were in range
+fn out_of_range_operand(mnemonic: &str, operands: &[&str]) -> Option<usize> {
+    let is_constant = |operand: &str| Node::parse(operand).is_ok_and(|n| n.symbols().is_empty());
+
+    INSTRUCTION_LIST
+        .iter()
+        .filter(|i| i.mnemonic() == mnemonic)
+        .find_map(|instruction| {
+            let types = instruction.expression().operands();
+            if types.iter().flatten().count() != operands.len() {
+                return None;
+            }
+            let mismatches: Vec<usize> = (0..operands.len())
+                .filter(|i| !types[*i].is_some_and(|t| t.match_with(operands[*i])))
+                .collect();
+            match mismatches.as_slice() {
+                [i] if types[*i].is_some_and(|t| t.is_immediate()) && is_constant(operands[*i]) => {
+                    Some(*i)
+                }
+                _ => None,
+            }
+        })
+}
+
+/// Find candidate whose name is the nearest to word
+/// Candidates which are too far from word aren't returned
+pub(crate) fn nearest<'a>(
+    word: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let limit = (word.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != word)
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Find text in source which isn't a part of longer keyword
+fn find_word(source: &str, text: &str) -> Option<usize> {
+    if text.is_empty() {
+        return None;
+    }
+    let is_boundary = |c: Option<char>, edge: Option<char>| {
+        !(c.is_some_and(is_keyword_char) && edge.is_some_and(is_keyword_char))
+    };
+
+    source.match_indices(text).map(|(i, _)| i).find(|i| {
+        is_boundary(source[..*i].chars().next_back(), text.chars().next())
+            && is_boundary(
+                source[i + text.len()..].chars().next(),
+                text.chars().next_back(),
+            )
+    })
+}
+
+impl Display for AsmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.span().location(), self.message())
+    }
+}
+
+impl std::error::Error for AsmError {}
@@ -0,0 +1,235 @@
+use super::{nearest, AsmError, Diagnostic, Span};
+use crate::{
+    functions::{is_keyword, is_keyword_char, split_mnemonic},
+    instruction::INSTRUCTION_LIST,
+    register::{Register, REGISTERS},
+};
+
+/// ANSI colors of parts of diagnostics
+const ERROR_COLOR: &str = "1;31";
+const WARNING_COLOR: &str = "1;33";
+const GUTTER_COLOR: &str = "1;34";
+const BOLD: &str = "1";
+
+/// Style of rendered diagnostics
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Style {
+    /// Plain text
+    #[default]
+    Plain,
+    /// Text colored with ANSI escape sequences
+    Ansi,
+}
+
+impl Style {
+    fn paint(self, text: &str, color: &str) -> String {
+        match self {
+            Style::Plain => text.to_string(),
+            Style::Ansi => format!("\x1b[{}m{}\x1b[0m", color, text),
+        }
+    }
+}
+
+/// Renderer of diagnostics
+/// Diagnostics are rendered with the source line, carets under the span and hints
+/// # Example
+/// ```
+/// use asm::{Asm, Renderer};
+/// let error = Asm::new("ret\nmvo rax, rbx").assemble().unwrap_err();
+///
+/// assert_eq!(
+///     "error: unknown mnemonic : mvo
+///  --> line 2, column 1
+///   |
+/// 2 | mvo rax, rbx
+///   | ^^^
+///   = help: did you mean `mov`?",
+///     Renderer::new().render_error(&error)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Renderer {
+    style: Style,
+}
+
+impl Renderer {
+    /// Construct Renderer which renders plain text
+    pub fn new() -> Self {
+        Renderer {
+            style: Style::Plain,
+        }
+    }
+
+    /// Set style of output
+    pub fn with_style(self, style: Style) -> Self {
+        Renderer { style }
+    }
+
+    /// Render error
+    pub fn render_error(&self, error: &AsmError) -> String {
+        self.render_parts(
+            ("error", ERROR_COLOR),
+            error.span(),
+            &error.message(),
+            &hints(error),
+        )
+    }
+
+    /// Render error or warning
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        match diagnostic {
+            Diagnostic::Error(error) => self.render_error(error),
+            Diagnostic::Warning(warning) => self.render_parts(
+                ("warning", WARNING_COLOR),
+                warning.span(),
+                &warning.message(),
+                &[],
+            ),
+        }
+    }
+
+    /// Render diagnostics separated by blank lines
+    pub fn render_all(&self, diagnostics: &[Diagnostic]) -> String {
+        let rendered: Vec<String> = diagnostics.iter().map(|d| self.render(d)).collect();
+        rendered.join("\n\n")
+    }
+
+    fn render_parts(
+        &self,
+        (severity, color): (&str, &str),
+        span: &Span,
+        message: &str,
+        hints: &[(&str, String)],
+    ) -> String {
+        let style = self.style;
+        let line_number = span.line().to_string();
+        let gutter = " ".repeat(line_number.len());
+        let bar = style.paint("|", GUTTER_COLOR);
+
+        // Carets are aligned with tabs in source
+        let source = span.location.source();
+        let offset = span.location.offset();
+        let start = span.range.start.saturating_sub(offset);
+        let end = span.range.end.saturating_sub(offset);
+        let indent: String = source
+            .get(..start)
+            .unwrap_or("")
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = source.get(start..end).map_or(0, |s| s.chars().count());
+        let carets = "^".repeat(width.max(1));
+
+        let mut lines = vec![
+            style.paint(severity, color) + &style.paint(&format!(": {}", message), BOLD),
+            format!(
+                "{}{} {}, column {}",
+                gutter,
+                style.paint("-->", GUTTER_COLOR),
+                span.location(),
+                span.column()
+            ),
+            format!("{} {}", gutter, bar),
+            format!(
+                "{} {} {}",
+                style.paint(&line_number, GUTTER_COLOR),
+                bar,
+                source
+            ),
+            format!(
+                "{} {} {}{}",
+                gutter,
+                bar,
+                indent,
+                style.paint(&carets, color)
+            ),
+        ];
+        for (kind, hint) in hints {
+            lines.push(format!(
+                "{} {} {}: {}",
+                gutter,
+                style.paint("=", GUTTER_COLOR),
+                style.paint(kind, BOLD),
+                hint
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Get hints like near names and forms of instruction
+fn hints(error: &AsmError) -> Vec<(&'static str, String)> {
+    let did_you_mean = |name: &str| ("help", format!("did you mean `{}`?", name));
+
+    match error {
+        AsmError::UnknownMnemonic { mnemonic, .. } => {
+            let mnemonics = INSTRUCTION_LIST.iter().map(|i| i.mnemonic());
+            nearest(mnemonic, mnemonics)
+                .map(did_you_mean)
+                .into_iter()
+                .collect()
+        }
+        AsmError::NoMatchingForm { instruction, .. } => {
+            let (mnemonic, operands) = split_mnemonic(instruction);
+            let mut hints = register_hints(operands);
+            hints.extend(forms(mnemonic).map(|forms| ("note", forms)));
+            hints
+        }
+        AsmError::AmbiguousOperandSize { instruction, .. } => {
+            let mut hints = vec![(
+                "help",
+                "specify size of memory operand like `qword ptr`".to_string(),
+            )];
+            hints.extend(forms(split_mnemonic(instruction).0).map(|forms| ("note", forms)));
+            hints
+        }
+        AsmError::InvalidMemoryOperand { operand, .. } => register_hints(operand),
+        AsmError::UndefinedLabel {
+            suggestion: Some(suggestion),
+            ..
+        } => vec![did_you_mean(suggestion)],
+        _ => Vec::new(),
+    }
+}
+
+/// Suggest registers for words which look like misspelled registers
+fn register_hints(text: &str) -> Vec<(&'static str, String)> {
+    text.split(|c: char| !is_keyword_char(c))
+        .filter(|word| is_keyword(word) && word.parse::<Register>().is_err())
+        .filter_map(|word| {
+            let register = nearest(word, REGISTERS.iter().map(|(name, _)| *name))?;
+            Some((
+                "help",
+                format!("`{}` isn't a register, did you mean `{}`?", word, register),
+            ))
+        })
+        .collect()
+}
+
+/// List operand types of all forms of mnemonic like `push accepts r64 | imm32`
+fn forms(mnemonic: &str) -> Option<String> {
+    let mut forms: Vec<String> = Vec::new();
+    for instruction in INSTRUCTION_LIST.iter().filter(|i| i.mnemonic() == mnemonic) {
+        let operands: Vec<&str> = instruction
+            .expression()
+            .operands()
+            .iter()
+            .flatten()
+            .map(|t| t.name())
+            .collect();
+        let form = if operands.is_empty() {
+            "no operands".to_string()
+        } else {
+            operands.join(", ")
+        };
+        if !forms.contains(&form) {
+            forms.push(form);
+        }
+    }
+
+    if forms.is_empty() {
+        None
+    } else {
+        Some(format!("{} accepts {}", mnemonic, forms.join(" | ")))
+    }
+}
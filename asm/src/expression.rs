@@ -0,0 +1,242 @@
+use crate::functions::{is_keyword, is_keyword_char};
+use util::functions::stoi;
+
+/// Binary operators from lowest precedence
+/// `//` is signed division of NASM, which is the same as `/`
+const BINARY_OPERATORS: [&[&str]; 10] = [
+    &["||"],
+    &["&&"],
+    &["|"],
+    &["^"],
+    &["&"],
+    &["==", "!="],
+    &["<=", ">=", "<", ">"],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "//", "%"],
+];
+
+/// Maximum count of tokens in expression
+/// Parser is recursive, so expressions are limited not to overflow stack
+const MAX_TOKENS: usize = 256;
+
+/// Token of expression
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token<'a> {
+    Number(i128),
+    Symbol(&'a str),
+    Operator(&'a str),
+    Open,
+    Close,
+}
+
+/// Parsed expression
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node<'a> {
+    Number(i128),
+    Symbol(&'a str),
+    Unary(&'a str, Box<Node<'a>>),
+    Binary(&'a str, Box<Node<'a>>, Box<Node<'a>>),
+}
+
+impl<'a> Node<'a> {
+    /// Parse expression
+    /// # Example
+    /// ```
+    /// use asm::expression::Node;
+    /// let node = Node::parse("(1 << 4) | end - start").unwrap();
+    ///
+    /// assert_eq!(vec!["end", "start"], node.symbols());
+    /// assert_eq!(Ok(19), node.evaluate(&|s| if s == "end" { Some(5) } else { Some(2) }));
+    /// ```
+    pub fn parse(expr: &'a str) -> Result<Self, String> {
+        let tokens = tokenize(expr)?;
+        if MAX_TOKENS < tokens.len() {
+            return Err("expression too long : ".to_string() + expr.trim());
+        }
+        let mut index = 0;
+        let node = parse_binary(&tokens, &mut index, 0)?;
+        if index != tokens.len() {
+            return Err("invalid expression : ".to_string() + expr.trim());
+        }
+        Ok(node)
+    }
+
+    /// Evaluate expression
+    /// Symbols are resolved by resolve
+    pub fn evaluate(&self, resolve: &impl Fn(&str) -> Option<i128>) -> Result<i128, String> {
+        match self {
+            Node::Number(n) => Ok(*n),
+            Node::Symbol(s) => resolve(s).ok_or_else(|| "undefined symbol : ".to_string() + s),
+            Node::Unary(operator, node) => {
+                let value = node.evaluate(resolve)?;
+                Ok(match *operator {
+                    "-" => value.wrapping_neg(),
+                    "~" => !value,
+                    "!" => (value == 0) as i128,
+                    _ => value,
+                })
+            }
+            Node::Binary(operator, lhs, rhs) => {
+                let lhs = lhs.evaluate(resolve)?;
+                let rhs = rhs.evaluate(resolve)?;
+                binary_operation(operator, lhs, rhs)
+            }
+        }
+    }
+
+    /// Get symbols referred in expression
+    pub fn symbols(&self) -> Vec<&'a str> {
+        let mut symbols = Vec::new();
+        self.collect_symbols(&mut symbols);
+        symbols
+    }
+
+    fn collect_symbols(&self, symbols: &mut Vec<&'a str>) {
+        match self {
+            Node::Number(_) => (),
+            Node::Symbol(s) => symbols.push(s),
+            Node::Unary(_, node) => node.collect_symbols(symbols),
+            Node::Binary(_, lhs, rhs) => {
+                lhs.collect_symbols(symbols);
+                rhs.collect_symbols(symbols);
+            }
+        }
+    }
+}
+
+/// Evaluate expression
+/// Symbols are resolved by resolve
+/// # Example
+/// ```
+/// use asm::expression::evaluate;
+/// assert_eq!(Ok(0x13), evaluate("(1 << 4) | 3", |_| None));
+/// assert_eq!(Ok(-6), evaluate("~SIZE + 1", |_| Some(6)));
+/// assert!(evaluate("1 / 0", |_| None).is_err());
+/// ```
+pub fn evaluate(expr: &str, resolve: impl Fn(&str) -> Option<i128>) -> Result<i128, String> {
+    Node::parse(expr)?.evaluate(&resolve)
+}
+
+/// If this is a symbol in expression
+/// `$` is the current location, `.` is the same as `$`, and `$$` is the start of section
+pub fn is_symbol(word: &str) -> bool {
+    word == "$" || word == "$$" || is_keyword(word)
+}
+
+fn binary_operation(operator: &str, lhs: i128, rhs: i128) -> Result<i128, String> {
+    let shift = || u32::try_from(rhs).ok().filter(|s| *s < i128::BITS);
+
+    Ok(match operator {
+        "||" => (lhs != 0 || rhs != 0) as i128,
+        "&&" => (lhs != 0 && rhs != 0) as i128,
+        "|" => lhs | rhs,
+        "^" => lhs ^ rhs,
+        "&" => lhs & rhs,
+        "==" => (lhs == rhs) as i128,
+        "!=" => (lhs != rhs) as i128,
+        "<=" => (lhs <= rhs) as i128,
+        ">=" => (lhs >= rhs) as i128,
+        "<" => (lhs < rhs) as i128,
+        ">" => (lhs > rhs) as i128,
+        "<<" => lhs << shift().ok_or("invalid shift")?,
+        ">>" => lhs >> shift().ok_or("invalid shift")?,
+        "+" => lhs.wrapping_add(rhs),
+        "-" => lhs.wrapping_sub(rhs),
+        "*" => lhs.wrapping_mul(rhs),
+        "/" | "//" => lhs.checked_div(rhs).ok_or("division by zero")?,
+        "%" => lhs.checked_rem(rhs).ok_or("division by zero")?,
+        _ => return Err("unknown operator : ".to_string() + operator),
+    })
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token<'_>>, String> {
+    const OPERATORS: [&str; 20] = [
+        "||", "&&", "==", "!=", "<=", ">=", "<<", ">>", "//", "|", "^", "&", "<", ">", "+", "-",
+        "*", "/", "%", "~",
+    ];
+
+    let mut tokens = Vec::new();
+    let mut rest = expr.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '(' || c == ')' {
+            tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            1
+        } else if c == '!' && !rest.starts_with("!=") {
+            tokens.push(Token::Operator("!"));
+            1
+        } else if let Some(operator) = OPERATORS.iter().find(|o| rest.starts_with(**o)) {
+            tokens.push(Token::Operator(operator));
+            operator.len()
+        } else if c == '.'
+            && !rest[1..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        {
+            tokens.push(Token::Symbol("$"));
+            1
+        } else if is_keyword_char(c) {
+            let len = rest
+                .find(|c: char| !is_keyword_char(c))
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            if c.is_ascii_digit() {
+                let value = stoi(word).ok_or_else(|| "invalid number : ".to_string() + word)?;
+                tokens.push(Token::Number(value));
+            } else if is_symbol(word) {
+                tokens.push(Token::Symbol(word));
+            } else {
+                return Err("invalid symbol : ".to_string() + word);
+            }
+            len
+        } else {
+            return Err("invalid character in expression : ".to_string() + expr.trim());
+        };
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+fn parse_binary<'a>(
+    tokens: &[Token<'a>],
+    index: &mut usize,
+    level: usize,
+) -> Result<Node<'a>, String> {
+    if level == BINARY_OPERATORS.len() {
+        return parse_unary(tokens, index);
+    }
+
+    let mut lhs = parse_binary(tokens, index, level + 1)?;
+    while let Some(Token::Operator(operator)) = tokens.get(*index) {
+        if !BINARY_OPERATORS[level].contains(operator) {
+            break;
+        }
+        *index += 1;
+        let rhs = parse_binary(tokens, index, level + 1)?;
+        lhs = Node::Binary(operator, Box::new(lhs), Box::new(rhs));
+    }
+    Ok(lhs)
+}
+
+fn parse_unary<'a>(tokens: &[Token<'a>], index: &mut usize) -> Result<Node<'a>, String> {
+    let token = tokens.get(*index).ok_or("unexpected end of expression")?;
+    *index += 1;
+
+    match *token {
+        Token::Number(n) => Ok(Node::Number(n)),
+        Token::Symbol(s) => Ok(Node::Symbol(s)),
+        Token::Operator(operator @ ("-" | "+" | "~" | "!")) => {
+            let node = parse_unary(tokens, index)?;
+            Ok(Node::Unary(operator, Box::new(node)))
+        }
+        Token::Open => {
+            let node = parse_binary(tokens, index, 0)?;
+            if tokens.get(*index) != Some(&Token::Close) {
+                return Err("unclosed parenthesis".to_string());
+            }
+            *index += 1;
+            Ok(node)
+        }
+        _ => Err("unexpected token in expression".to_string()),
+    }
+}
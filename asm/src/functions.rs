@@ -1,64 +1,117 @@
-use crate::{assembler::Label, register::Register};
+use crate::{
+    expression::Node,
+    instruction::{OperandSize, INSTRUCTION_LIST, MAX_OPERANDS},
+    line::Line,
+    register::Register,
+    symbol::SymbolTable,
+    syntax::Syntax,
+};
+use std::ops::Range;
 use util::functions::{result_to_option, stoi};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Relocation<'a, T> {
     Value(T),
-    Label(&'a str),
+    /// Label with addend
+    Label(&'a str, T),
+    /// Expression which refers labels or current location
+    Expression(&'a str),
 }
 
 impl<'a> Relocation<'a, i128> {
-    pub fn relocate_imm(self, labels: &[Label<'a>], offset: usize) -> Result<i128, String> {
-        match self {
-            Relocation::Value(v) => Ok(v),
-            Relocation::Label(l) => {
-                for i in labels {
-                    if i.name() == l {
-                        return Ok(i.offset() as i128 - offset as i128);
-                    }
-                }
-                Err("unknown label : ".to_string() + l)
-            }
+    /// Parse expression of operand
+    /// Expressions which don't refer symbols are evaluated here
+    /// # Example
+    /// ```
+    /// use asm::functions::Relocation;
+    /// assert_eq!(Some(Relocation::Value(19)), Relocation::parse("(1 << 4) | 3"));
+    /// assert_eq!(Some(Relocation::Label("table", -8)), Relocation::parse("table - 8"));
+    /// assert_eq!(Some(Relocation::Expression("$ - start")), Relocation::parse("$ - start"));
+    /// assert_eq!(None, Relocation::parse("rax + 1"));
+    /// ```
+    pub fn parse(expr: &'a str) -> Option<Self> {
+        let constant = |node: &Node| node.evaluate(&|_| None).ok();
+        let label = |node: &Node<'a>| match node {
+            Node::Symbol(s) if !s.starts_with('$') => Some(*s),
+            _ => None,
+        };
+
+        let node = Node::parse(expr).ok()?;
+        let symbols = node.symbols();
+        if symbols.iter().any(|s| s.parse::<Register>().is_ok()) {
+            return None;
+        }
+        if symbols.is_empty() {
+            return Some(Relocation::Value(constant(&node)?));
         }
+
+        let relocation = match &node {
+            Node::Symbol(_) => label(&node).map(|l| Relocation::Label(l, 0)),
+            Node::Binary("+", lhs, rhs) => match (label(lhs), label(rhs)) {
+                (Some(l), None) => constant(rhs).map(|a| Relocation::Label(l, a)),
+                (None, Some(l)) => constant(lhs).map(|a| Relocation::Label(l, a)),
+                _ => None,
+            },
+            Node::Binary("-", lhs, rhs) => label(lhs)
+                .zip(constant(rhs).and_then(i128::checked_neg))
+                .map(|(l, a)| Relocation::Label(l, a)),
+            _ => None,
+        };
+        Some(relocation.unwrap_or(Relocation::Expression(expr.trim())))
+    }
+
+    /// Get value relative to offset
+    /// Values are returned as they are
+    pub fn relocate_imm(self, symbols: &SymbolTable, offset: usize) -> Result<i128, String> {
+        let value = match self {
+            Relocation::Value(v) => return Ok(v),
+            Relocation::Label(l, addend) => symbols
+                .value(l)
+                .ok_or_else(|| "unknown label : ".to_string() + l)?
+                .wrapping_add(addend),
+            Relocation::Expression(e) => symbols.evaluate(e)?,
+        };
+        value
+            .checked_sub(offset as i128)
+            .ok_or_else(|| "value out of range".to_string())
+    }
+
+    /// Convert into displacement
+    pub fn to_disp(self) -> Option<Relocation<'a, i32>> {
+        Some(match self {
+            Relocation::Value(v) => Relocation::Value(i32::try_from(v).ok()?),
+            Relocation::Label(l, addend) => Relocation::Label(l, i32::try_from(addend).ok()?),
+            Relocation::Expression(e) => Relocation::Expression(e),
+        })
     }
 }
 
 impl<'a> Relocation<'a, i32> {
-    pub fn relocate_disp(self, label: &[Label<'a>], next_offset: usize) -> Result<i32, String> {
-        match self {
-            Relocation::Value(v) => Ok(v),
-            Relocation::Label(l) => {
-                for i in label {
-                    if i.name() == l {
-                        return Ok((i.offset() as isize - next_offset as isize) as i32);
-                    }
-                }
-                Err("unknown label : ".to_string() + l)
-            }
-        }
+    /// Get displacement relative to offset
+    pub fn relocate_disp(self, symbols: &SymbolTable, offset: usize) -> Result<i32, String> {
+        let relocation = match self {
+            Relocation::Value(v) => return Ok(v),
+            Relocation::Label(l, addend) => Relocation::Label(l, addend as i128),
+            Relocation::Expression(e) => Relocation::Expression(e),
+        };
+        let disp = relocation.relocate_imm(symbols, offset)?;
+        i32::try_from(disp).map_err(|_| "displacement out of range".to_string())
     }
 }
 
-pub fn parse_rm(
-    mut expr: &str,
-    address_size: char,
-) -> Option<(Relocation<'_, i32>, Register, Option<(Register, u8)>)> {
+/// Memory reference operand (disp, base, (index, scale))
+/// Base is None for `disp[,index,scale]` which has only index
+pub type RmRef<'a> = (
+    Relocation<'a, i32>,
+    Option<Register>,
+    Option<(Register, u8)>,
+);
+
+pub fn parse_rm(mut expr: &str, address_size: char) -> Option<RmRef<'_>> {
     // disp[base, index, scale]
     let disp: Relocation<'_, i32> = if !expr.starts_with('[') {
         let disp_expr = expr.split_once('[')?.0;
-        if let Some(value) = stoi(disp_expr) {
-            if i32::MIN as i128 <= value && value <= i32::MAX as i128 {
-                Relocation::Value(value as i32)
-            } else {
-                return None;
-            }
-        } else {
-            if is_keyword(disp_expr) {
-                Relocation::Label(disp_expr)
-            } else {
-                return None;
-            }
-        }
+        Relocation::parse(disp_expr)?.to_disp()?
     } else {
         Relocation::Value(0)
     };
@@ -74,16 +127,19 @@ pub fn parse_rm(
     expr = &expr[..expr.len() - ']'.len_utf8()];
     let mut arguments_iter = expr.split(',');
 
-    let base = result_to_option(arguments_iter.next()?.parse::<Register>())?;
+    let base = match arguments_iter.next()?.trim() {
+        "" => None,
+        s => Some(result_to_option(s.parse::<Register>())?),
+    };
 
     let index = if let Some(s) = arguments_iter.next() {
-        result_to_option(s.parse::<Register>())?
+        result_to_option(s.trim().parse::<Register>())?
     } else {
-        return Some((disp, base, None));
+        return base.map(|base| (disp, Some(base), None));
     };
 
     let scale = if let Some(s) = arguments_iter.next() {
-        let value = stoi(s)?;
+        let value = stoi(s.trim())?;
         if value == 1 || value == 2 || value == 4 || value == 8 {
             value as u8
         } else {
@@ -96,16 +152,300 @@ pub fn parse_rm(
     Some((disp, base, Some((index, scale))))
 }
 
+/// Convert memory operands of instruction into `disp[base,index,scale]size`
+/// Size of memory operand is inferred if only one size matches with any instruction
+/// Immediates don't fix the size, so it is ambiguous without register operands
+/// unless operand size of the mnemonic is fixed like `push`
+/// # Example
+/// ```
+/// use asm::functions::canonical_instruction;
+/// assert_eq!(Ok("mov 8[rbp]q, rcx".to_string()), canonical_instruction("mov [rbp + 8], rcx"));
+/// assert_eq!(Ok("push [rdi]q".to_string()), canonical_instruction("push [rdi]"));
+/// assert!(canonical_instruction("add [rax], 1").is_err());
+/// assert!(canonical_instruction("mov [rax], 1").is_err());
+/// ```
+pub fn canonical_instruction(line: &str) -> Result<String, String> {
+    const SUFFIXES: [char; 4] = ['b', 'w', 'd', 'q'];
+
+    // Size is Some(None) for memory operands without size
+    let (mnemonic, operands) = split_mnemonic(line);
+    let mut converted: Vec<(String, Option<Option<char>>)> = Vec::new();
+    for operand in split_operands(operands) {
+        match convert_memory_operand(operand) {
+            Some(result) => {
+                let (reference, size) = result?;
+                converted.push((reference, Some(size)));
+            }
+            None => converted.push((operand.to_string(), None)),
+        }
+    }
+    if converted.iter().all(|(_, size)| size.is_none()) {
+        return Ok(line.to_string());
+    }
+    let unsized_memory = converted.iter().any(|(_, size)| *size == Some(None));
+    let has_register = converted
+        .iter()
+        .any(|(operand, size)| size.is_none() && operand.trim().parse::<Register>().is_ok());
+    if unsized_memory && !has_register && !has_fixed_operand_size(mnemonic) {
+        return Err("ambiguous operand size".to_string());
+    }
+
+    let build = |suffix: char| {
+        let operands: Vec<String> = converted
+            .iter()
+            .map(|(operand, size)| match size {
+                Some(size) => format!("{}{}", operand, size.unwrap_or(suffix)),
+                None => operand.clone(),
+            })
+            .collect();
+        format!("{} {}", mnemonic, operands.join(", "))
+    };
+
+    let mut candidates: Vec<String> = SUFFIXES
+        .iter()
+        .map(|suffix| build(*suffix))
+        .filter(|line| Line::Instruction(line).is_valid_instruction())
+        .collect();
+    candidates.dedup();
+    match candidates.as_slice() {
+        [] => Ok(build('q')),
+        [line] => Ok(line.clone()),
+        _ => Err("ambiguous operand size".to_string()),
+    }
+}
+
+/// If operand size of mnemonic is always 64 bits like near branches, push and pop
+fn has_fixed_operand_size(mnemonic: &str) -> bool {
+    let mut forms = INSTRUCTION_LIST
+        .iter()
+        .filter(|i| i.mnemonic() == mnemonic)
+        .peekable();
+    forms.peek().is_some() && forms.all(|i| i.encoding().default_operand_size() == OperandSize::Oq)
+}
+
+/// Convert memory operand like `qword ptr [base + index*scale + disp]` into `disp[base,index,scale]`
+/// Returns None if this isn't a memory operand of this syntax, and size suffix if size is specified
+/// # Example
+/// ```
+/// use asm::functions::convert_memory_operand;
+/// assert_eq!(
+///     Some(Ok(("16[rax,rbx,8]".to_string(), Some('q')))),
+///     convert_memory_operand("qword ptr [rax + rbx*8 + 16]")
+/// );
+/// assert_eq!(
+///     Some(Ok(("label-8[rip]".to_string(), None))),
+///     convert_memory_operand("[rip + label - 8]")
+/// );
+/// assert_eq!(
+///     Some(Ok(("16[,rcx,8]".to_string(), None))),
+///     convert_memory_operand("[rcx*8 + 16]")
+/// );
+/// assert_eq!(None, convert_memory_operand("8[rbp]q"));
+/// ```
+pub fn convert_memory_operand(operand: &str) -> Option<Result<(String, Option<char>), String>> {
+    const SIZES: [(&str, char); 4] = [("byte", 'b'), ("word", 'w'), ("dword", 'd'), ("qword", 'q')];
+
+    let operand = operand.trim();
+    let (prefix, rest) = operand.split_once('[')?;
+    let inner = rest.strip_suffix(']')?;
+
+    let size = match prefix.split_whitespace().collect::<Vec<&str>>().as_slice() {
+        [] => None,
+        [size] | [size, "ptr"] => match SIZES.iter().find(|(name, _)| name == size) {
+            Some((_, suffix)) => Some(*suffix),
+            None => return Some(Err("invalid operand size : ".to_string() + size)),
+        },
+        _ => return None,
+    };
+    Some(convert_memory_reference(inner).map(|reference| (reference, size)))
+}
+
+/// Convert `base + index*scale + disp` into `disp[base,index,scale]`
+fn convert_memory_reference(expr: &str) -> Result<String, String> {
+    let error = || "invalid memory operand : ".to_string() + expr.trim();
+    let mut base: Option<&str> = None;
+    let mut index: Option<(&str, &str)> = None;
+    let mut disp = String::new();
+
+    let mut start = 0;
+    let mut depth: usize = 0;
+    let mut terms = Vec::new();
+    for (i, c) in expr.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            '+' | '-' if depth == 0 => {
+                terms.push(&expr[start..i]);
+                start = i;
+            }
+            _ => (),
+        }
+    }
+    terms.push(&expr[start..]);
+
+    for term in terms {
+        let (sign, body) = match term.trim().strip_prefix('-') {
+            Some(body) => ("-", body.trim()),
+            None => ("+", term.trim().trim_start_matches('+').trim()),
+        };
+        let register = |s: &str| s.trim().parse::<Register>().is_ok();
+        let scaled = body.split_once('*').and_then(|(lhs, rhs)| {
+            if register(lhs) {
+                Some((lhs.trim(), rhs.trim()))
+            } else if register(rhs) {
+                Some((rhs.trim(), lhs.trim()))
+            } else {
+                None
+            }
+        });
+
+        if body.is_empty() {
+            if sign == "-" || !disp.is_empty() || base.is_some() || index.is_some() {
+                return Err(error());
+            }
+        } else if register(body) || scaled.is_some() {
+            if sign == "-" {
+                return Err(error());
+            }
+            match scaled {
+                Some(scaled) if index.is_none() => index = Some(scaled),
+                None if base.is_none() => base = Some(body),
+                None if index.is_none() => index = Some((body, "1")),
+                _ => return Err(error()),
+            }
+        } else {
+            if !disp.is_empty() || sign == "-" {
+                disp += sign;
+            }
+            disp += body;
+        }
+    }
+
+    Ok(match (base, index) {
+        (base, Some((index, scale))) => {
+            format!("{}[{},{},{}]", disp, base.unwrap_or(""), index, scale)
+        }
+        (Some(base), None) => format!("{}[{}]", disp, base),
+        (None, None) => return Err(error()),
+    })
+}
+
+/// Character which separates statements in a line
+/// `;` can't be used since it starts a comment
+/// `@` followed by a keyword character is a part of operand like `@progbits` and `foo@PLT`
+pub const STATEMENT_SEPARATOR: char = '@';
+
 /// If this is a label
+/// Numeric labels like `1:` are also labels
 pub fn is_label(mut line: &str) -> bool {
     line = line.trim();
     if !line.ends_with(':') {
         return false;
     }
-    line = &line[..line.len() - ':'.len_utf8()].trim();
-    is_keyword(line)
+    line = line[..line.len() - ':'.len_utf8()].trim();
+    is_label_name(line)
 }
 
+fn is_label_name(name: &str) -> bool {
+    is_keyword(name) || (!name.is_empty() && name.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Split line without comments into statements
+/// Statements are separated by STATEMENT_SEPARATOR, and labels at the start of statements are split from them
+/// `\@` of macros and `@` followed by a keyword character aren't separators,
+/// so statements like `dec rcx@jnz loop` must have spaces around `@`, and adjacent_separator finds them
+/// # Example
+/// ```
+/// use asm::functions::split_statements;
+/// assert_eq!(
+///     vec!["loop:", "dec rcx", "1:", ".ascii \"@:\""],
+///     split_statements("loop: dec rcx @ 1: .ascii \"@:\" @")
+/// );
+/// assert_eq!(vec!["call foo@PLT"], split_statements("call foo@PLT"));
+/// ```
+pub fn split_statements(line: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut previous = ' ';
+    let mut chars = line.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if is_quote(c) {
+            let end = string_end(line, i).unwrap_or(line.len());
+            while chars.clone().next().is_some_and(|(j, _)| j < end) {
+                chars.next();
+            }
+        } else if c == STATEMENT_SEPARATOR
+            && previous != '\\'
+            && !line[i + c.len_utf8()..].starts_with(is_keyword_char)
+        {
+            pieces.push(&line[start..i]);
+            start = i + c.len_utf8();
+        }
+        previous = c;
+    }
+    pieces.push(&line[start..]);
+
+    let mut statements = Vec::new();
+    for mut piece in pieces {
+        while let Some((label, rest)) = piece.split_once(':') {
+            if !is_label_name(label.trim()) {
+                break;
+            }
+            statements.push(piece[..label.len() + 1].trim());
+            piece = rest;
+        }
+        if !piece.trim().is_empty() {
+            statements.push(piece.trim());
+        }
+    }
+
+    statements
+}
+
+/// Find `@` which is followed by a mnemonic like `ret@ret` and `dec rcx@jnz loop`
+/// It is a part of operand by the rule of split_statements, but it is likely meant to separate statements
+/// # Example
+/// ```
+/// use asm::functions::adjacent_separator;
+/// assert_eq!(Some(7), adjacent_separator("dec rcx@jnz loop"));
+/// assert_eq!(None, adjacent_separator("call foo@PLT"));
+/// assert_eq!(None, adjacent_separator(".ascii \"@ret\""));
+/// ```
+pub fn adjacent_separator(statement: &str) -> Option<usize> {
+    let mut previous = ' ';
+    let mut chars = statement.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if is_quote(c) {
+            let end = string_end(statement, i).unwrap_or(statement.len());
+            while chars.clone().next().is_some_and(|(j, _)| j < end) {
+                chars.next();
+            }
+        } else if c == STATEMENT_SEPARATOR && previous != '\\' {
+            let rest = &statement[i + c.len_utf8()..];
+            let word = &rest[..rest.find(|c| !is_keyword_char(c)).unwrap_or(rest.len())];
+            if INSTRUCTION_LIST.iter().any(|i| i.mnemonic() == word) {
+                return Some(i);
+            }
+        }
+        previous = c;
+    }
+
+    None
+}
+
+/// If this is a keyword like mnemonic, label or symbol name
+/// Keywords start with a letter, `_` or `.`, and may contain digits and `$` after the first character
+/// # Example
+/// ```
+/// use asm::functions::is_keyword;
+/// assert!(is_keyword("_start"));
+/// assert!(is_keyword(".Lloop"));
+/// assert!(is_keyword("main.L1$0"));
+/// assert!(!is_keyword("1f"));
+/// assert!(!is_keyword("$"));
+/// ```
 pub fn is_keyword(mut word: &str) -> bool {
     word = word.trim();
     let mut word_chars = word.chars();
@@ -113,12 +453,15 @@ pub fn is_keyword(mut word: &str) -> bool {
     let Some(first_char) = word_chars.next() else {
         return false;
     };
-    if !first_char.is_ascii_alphabetic() {
+    if !first_char.is_ascii_alphabetic() && first_char != '_' && first_char != '.' {
+        return false;
+    }
+    if word == "." {
         return false;
     }
 
     for c in word_chars {
-        if !c.is_ascii_alphanumeric() {
+        if !is_keyword_char(c) {
             return false;
         }
     }
@@ -126,33 +469,364 @@ pub fn is_keyword(mut word: &str) -> bool {
     true
 }
 
+/// If this character can be a part of keyword
+pub fn is_keyword_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '$'
+}
+
 /// If this is a assembler command
 pub fn is_asm_command(mut line: &str) -> bool {
     line = line.trim();
     if !line.starts_with('.') {
         return false;
     }
-    line = &line[1..].trim();
-    is_keyword(line)
+    line = &line[1..];
+    let name = line.split_ascii_whitespace().next().unwrap_or("");
+    line.starts_with(name) && is_keyword(name)
+}
+
+/// Split comma separated arguments of assembler command
+/// Commas in string literals are ignored, and quotes are `"`, `'` and `` ` ``
+pub fn split_arguments(expr: &str) -> Vec<&str> {
+    let mut arguments = Vec::new();
+    if expr.trim().is_empty() {
+        return arguments;
+    }
+
+    let mut start = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in expr.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && q == '"' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+        } else if is_quote(c) {
+            quote = Some(c);
+        } else if c == ',' {
+            arguments.push(expr[start..i].trim());
+            start = i + 1;
+        }
+    }
+    arguments.push(expr[start..].trim());
+
+    arguments
+}
+
+/// Find start of comment in line
+/// Comments are `;`, `#`, `//` and `/* */`, and they are ignored in string literals
+/// NASM has only `;` comments since `//` is an operator there
+pub fn comment_start(line: &str, syntax: Syntax) -> Option<usize> {
+    let mut quote = None;
+    let mut escaped = false;
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && q == '"' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+        } else {
+            match (c, chars.peek().map(|(_, c)| *c)) {
+                (c, _) if is_quote(c) => quote = Some(c),
+                (';', _) => return Some(i),
+                ('#', _) | ('/', Some('/' | '*')) if syntax != Syntax::Nasm => return Some(i),
+                _ => (),
+            }
+        }
+    }
+
+    None
+}
+
+/// If this character starts string literal
+pub fn is_quote(c: char) -> bool {
+    c == '"' || c == '\'' || c == '`'
+}
+
+/// Find end of string literal which starts at start
+/// Escape sequences are available in `"` and `` ` ``
+pub fn string_end(line: &str, start: usize) -> Option<usize> {
+    let quote = line[start..].chars().next().filter(|c| is_quote(*c))?;
+    let mut escaped = false;
+
+    for (i, c) in line[start + 1..].char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && quote != '\'' {
+            escaped = true;
+        } else if c == quote {
+            return Some(start + 1 + i + 1);
+        }
+    }
+
+    None
+}
+
+/// Remove comments from line
+/// Returns the rest of line and if a block comment continues to the next line
+pub fn strip_comment(mut line: &str, in_block: bool, syntax: Syntax) -> (String, bool) {
+    let mut stripped = String::with_capacity(line.len());
+
+    if in_block {
+        match line.find("*/") {
+            Some(end) => line = &line[end + 2..],
+            None => return (stripped, true),
+        }
+    }
+
+    while let Some(start) = comment_start(line, syntax) {
+        stripped += &line[..start];
+        if !line[start..].starts_with("/*") {
+            return (stripped, false);
+        }
+        match line[start + 2..].find("*/") {
+            Some(end) => {
+                stripped.push(' ');
+                line = &line[start + 2 + end + 2..];
+            }
+            None => return (stripped, true),
+        }
+    }
+    stripped += line;
+
+    (stripped, false)
+}
+
+/// Remove comments from source
+/// Line breaks in block comments are kept, so line numbers don't change
+/// # Example
+/// ```
+/// use asm::{functions::strip_comments, syntax::Syntax};
+/// let source = "ret ; return\n/* block\ncomment */ .ascii \"#;\" // string";
+/// assert_eq!("ret \n\n .ascii \"#;\" ", strip_comments(source, Syntax::Intel));
+/// assert_eq!("mov rax, 10 // 3 ", strip_comments("mov rax, 10 // 3 ; 3", Syntax::Nasm));
+/// ```
+pub fn strip_comments(source: &str, syntax: Syntax) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut in_block = false;
+
+    for (i, line) in source.lines().enumerate() {
+        if i != 0 {
+            stripped.push('\n');
+        }
+        let (line, block) = strip_comment(line, in_block, syntax);
+        stripped += &line;
+        in_block = block;
+    }
+
+    stripped
+}
+
+/// Parse string literal with escape sequences
+/// # Example
+/// ```
+/// use asm::functions::parse_string_literal;
+/// assert_eq!(b"a\n\x7f\0".to_vec(), parse_string_literal(r#""a\n\x7f\0""#).unwrap());
+/// ```
+pub fn parse_string_literal(mut expr: &str) -> Option<Vec<u8>> {
+    expr = expr.trim();
+    if expr.len() < 2 || !expr.starts_with('"') || !expr.ends_with('"') {
+        return None;
+    }
+    expr = &expr[1..expr.len() - 1];
+
+    let mut bytes = Vec::new();
+    let mut chars = expr.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return None,
+            '\\' => {
+                let value = match chars.next()? {
+                    'n' => b'\n',
+                    't' => b'\t',
+                    'r' => b'\r',
+                    'a' => 0x07,
+                    'b' => 0x08,
+                    'f' => 0x0c,
+                    'v' => 0x0b,
+                    'e' => 0x1b,
+                    '\\' => b'\\',
+                    '"' => b'"',
+                    '\'' => b'\'',
+                    'x' => {
+                        let mut value: u32 = 0;
+                        let mut digits = 0;
+                        while let Some(d) = chars.peek().and_then(|d| d.to_digit(16)) {
+                            if digits == 2 {
+                                break;
+                            }
+                            value = value * 16 + d;
+                            digits += 1;
+                            chars.next();
+                        }
+                        if digits == 0 {
+                            return None;
+                        }
+                        value as u8
+                    }
+                    d @ '0'..='7' => {
+                        let mut value = d.to_digit(8)?;
+                        for _ in 0..2 {
+                            match chars.peek().and_then(|d| d.to_digit(8)) {
+                                Some(d) => {
+                                    value = value * 8 + d;
+                                    chars.next();
+                                }
+                                None => break,
+                            }
+                        }
+                        if value > u8::MAX as u32 {
+                            return None;
+                        }
+                        value as u8
+                    }
+                    _ => return None,
+                };
+                bytes.push(value);
+            }
+            _ => {
+                let mut buff = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buff).as_bytes());
+            }
+        }
+    }
+
+    Some(bytes)
 }
 
 /// If this is a instruction
 pub fn is_instruction(line: &str) -> bool {
-    let mut line_split = line.split(' ');
+    let (mnemonic, operands) = split_mnemonic(line);
+    let operands = split_operands(operands);
 
-    let Some(mnemonic) = line_split.next() else {
-        return false;
-    };
-    if !is_keyword(mnemonic) {
-        return false;
+    is_keyword(mnemonic) && operands.len() <= MAX_OPERANDS && operands.iter().all(|o| !o.is_empty())
+}
+
+/// Split pseudo prefix like `{disp8}` from instruction
+/// # Example
+/// ```
+/// use asm::functions::split_pseudo_prefix;
+/// assert_eq!((Some("disp8"), " jmp label"), split_pseudo_prefix("{disp8} jmp label"));
+/// assert_eq!((None, "jmp label"), split_pseudo_prefix("jmp label"));
+/// ```
+pub fn split_pseudo_prefix(line: &str) -> (Option<&str>, &str) {
+    match line
+        .trim_start()
+        .strip_prefix('{')
+        .and_then(|s| s.split_once('}'))
+    {
+        Some((prefix, rest)) => (Some(prefix.trim()), rest),
+        None => (None, line),
     }
+}
 
-    let Some(_) = line_split.next() else {
-        return true;
-    };
-    let Some(_) = line_split.next() else {
-        return true;
-    };
+/// Split instruction into mnemonic and operands
+pub fn split_mnemonic(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(char::is_whitespace) {
+        Some((mnemonic, operands)) => (mnemonic, operands.trim()),
+        None => (line, ""),
+    }
+}
+
+/// Split operands of instruction
+/// Operands are separated by commas, or by whitespace in the old syntax like `mov rax rdi`
+/// Pieces separated by whitespace are joined into an expression by binary operators between them
+/// `+` and `-` after a register or memory operand are unary like `mov rax -1`
+/// Size keywords like `qword ptr` are kept together with the memory operand after them
+/// Separators in brackets and parentheses are ignored
+/// # Example
+/// ```
+/// use asm::functions::split_operands;
+/// assert_eq!(vec!["rax", "rdi"], split_operands("rax,\trdi"));
+/// assert_eq!(vec!["8[rbp, rcx, 4]q", "rax"], split_operands("8[rbp, rcx, 4]q rax"));
+/// assert_eq!(vec!["1 + (2, 3)"], split_operands("  1 + (2, 3)"));
+/// assert_eq!(vec!["end -8"], split_operands("end -8"));
+/// assert_eq!(vec!["qword [rax]"], split_operands("qword [rax]"));
+/// assert_eq!(vec!["rax", "-1"], split_operands("rax -1"));
+/// assert_eq!(vec!["rax", "rbx", "4"], split_operands("rax rbx 4"));
+/// ```
+pub fn split_operands(operands: &str) -> Vec<&str> {
+    const SIZE_KEYWORDS: [&str; 5] = ["byte", "word", "dword", "qword", "ptr"];
+
+    let operands = operands.trim();
+    if operands.is_empty() {
+        return Vec::new();
+    }
+
+    let split = split_outside_brackets(operands, |c| c == ',');
+    if 1 < split.len() {
+        return split;
+    }
+
+    let mut groups: Vec<Range<usize>> = Vec::new();
+    let mut previous = "";
+    for range in ranges_outside_brackets(operands, char::is_whitespace) {
+        if range.is_empty() {
+            continue;
+        }
+        let piece = &operands[range.clone()];
+        match groups.last_mut() {
+            Some(group)
+                if SIZE_KEYWORDS.contains(&previous)
+                    || continues_expression(&operands[group.clone()], previous, piece) =>
+            {
+                group.end = range.end
+            }
+            _ => groups.push(range),
+        }
+        previous = piece;
+    }
+    groups.into_iter().map(|r| &operands[r]).collect()
+}
+
+/// If piece separated by whitespace continues expression of the previous operand
+/// like `-8` of `end -8`
+fn continues_expression(operand: &str, previous: &str, piece: &str) -> bool {
+    const OPERATOR_CHARS: &str = "|^&=!<>+-*/%~";
+    const BINARY_OPERATOR_CHARS: &str = "|^&=<>*/%";
+
+    let is_register_or_memory = operand.parse::<Register>().is_ok() || operand.contains('[');
+    previous.ends_with(|c| OPERATOR_CHARS.contains(c))
+        || piece.starts_with(|c| BINARY_OPERATOR_CHARS.contains(c))
+        || piece.starts_with("!=")
+        || (piece.starts_with(['+', '-']) && !is_register_or_memory)
+}
+
+fn split_outside_brackets(expr: &str, is_separator: impl Fn(char) -> bool) -> Vec<&str> {
+    ranges_outside_brackets(expr, is_separator)
+        .into_iter()
+        .map(|r| expr[r].trim())
+        .collect()
+}
+
+/// Get ranges of pieces split by separators out of brackets and parentheses
+fn ranges_outside_brackets(expr: &str, is_separator: impl Fn(char) -> bool) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut depth: usize = 0;
+    let mut start = 0;
+
+    for (i, c) in expr.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && is_separator(c) => {
+                ranges.push(start..i);
+                start = i + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    ranges.push(start..expr.len());
 
-    line_split.next().is_none()
+    ranges
 }
@@ -1,14 +1,17 @@
 use crate::{
-    functions::{is_keyword, parse_rm, Relocation},
+    functions::{parse_rm, Relocation},
     line::Line,
     register::Register,
 };
 use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
-use util::{functions::stoi, svec::SVec};
+use util::svec::SVec;
 
 pub use instruction_database::INSTRUCTION_LIST;
 mod instruction_database;
 
+/// Max count of operands of instruction
+pub const MAX_OPERANDS: usize = 3;
+
 /// Instruction properties
 #[derive(Clone, Copy, Debug)]
 pub struct Instruction {
@@ -104,16 +107,16 @@ impl Eq for OperandSize {}
 
 impl PartialOrd for OperandSize {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let lhs = *self as usize;
-        let rhs = *other as usize;
-        lhs.partial_cmp(&rhs)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for OperandSize {
     // Required method
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).expect("unknown error")
+        let lhs = *self as usize;
+        let rhs = *other as usize;
+        lhs.cmp(&rhs)
     }
 }
 
@@ -157,7 +160,7 @@ pub enum OpecodeRegisterRule {
 #[derive(Clone, Copy, Debug)]
 pub struct Expression {
     mnemonic: &'static str,
-    operands: [Option<OperandType>; 2],
+    operands: [Option<OperandType>; MAX_OPERANDS],
 }
 
 impl Expression {
@@ -167,7 +170,7 @@ impl Expression {
     }
 
     /// Get operand types
-    pub const fn operands(&self) -> [Option<OperandType>; 2] {
+    pub const fn operands(&self) -> [Option<OperandType>; MAX_OPERANDS] {
         self.operands
     }
 
@@ -185,14 +188,15 @@ impl Expression {
             return false;
         };
 
-        for i in 0..2 {
-            if let Some(operand_type) = self.operands[i] {
-                let Some(operand) = operands[i] else {
-                    return false;
-                };
-                if !operand_type.match_with(operand) {
-                    return false;
+        for (operand_type, operand) in self.operands.iter().zip(operands) {
+            match (operand_type, operand) {
+                (Some(operand_type), Some(operand)) => {
+                    if !operand_type.match_with(operand) {
+                        return false;
+                    }
                 }
+                (None, None) => (),
+                _ => return false,
             }
         }
 
@@ -201,12 +205,7 @@ impl Expression {
 
     /// Get operand index by operand type
     pub fn get_operand_index_by_type(&self, operand_type: OperandType) -> Option<usize> {
-        for i in 0..2 {
-            if self.operands[i] == Some(operand_type) {
-                return Some(i);
-            }
-        }
-        None
+        (0..MAX_OPERANDS).find(|&i| self.operands[i] == Some(operand_type))
     }
 }
 
@@ -217,6 +216,9 @@ pub enum OperandType {
     Ax,
     Eax,
     Rax,
+    Cl,
+    /// Constant 1 like shift count of `shl rax, 1`
+    One,
     Rel8,
     Rel16,
     Rel32,
@@ -228,6 +230,10 @@ pub enum OperandType {
     Imm16,
     Imm32,
     Imm64,
+    /// Imm8 which is sign-extended to operand size like `add rax, -1`
+    SImm8,
+    /// Imm32 which is sign-extended to 64 bits like `add rax, -1`
+    SImm32,
     Rm8,
     Rm16,
     Rm32,
@@ -242,6 +248,8 @@ impl OperandType {
             OperandType::Ax => OperandSize::Ow,
             OperandType::Eax => OperandSize::Od,
             OperandType::Rax => OperandSize::Oq,
+            OperandType::Cl => OperandSize::Ob,
+            OperandType::One => OperandSize::Ob,
             OperandType::Rel8 => OperandSize::Ob,
             OperandType::Rel16 => OperandSize::Ow,
             OperandType::Rel32 => OperandSize::Od,
@@ -253,6 +261,8 @@ impl OperandType {
             OperandType::Imm16 => OperandSize::Ow,
             OperandType::Imm32 => OperandSize::Od,
             OperandType::Imm64 => OperandSize::Oq,
+            OperandType::SImm8 => OperandSize::Ob,
+            OperandType::SImm32 => OperandSize::Od,
             OperandType::Rm8 => OperandSize::Ob,
             OperandType::Rm16 => OperandSize::Ow,
             OperandType::Rm32 => OperandSize::Od,
@@ -260,6 +270,64 @@ impl OperandType {
         }
     }
 
+    /// Get name of operand type like `rm64`
+    pub const fn name(self) -> &'static str {
+        match self {
+            OperandType::Al => "al",
+            OperandType::Ax => "ax",
+            OperandType::Eax => "eax",
+            OperandType::Rax => "rax",
+            OperandType::Cl => "cl",
+            OperandType::One => "1",
+            OperandType::Rel8 => "rel8",
+            OperandType::Rel16 => "rel16",
+            OperandType::Rel32 => "rel32",
+            OperandType::R8 => "r8",
+            OperandType::R16 => "r16",
+            OperandType::R32 => "r32",
+            OperandType::R64 => "r64",
+            OperandType::Imm8 => "imm8",
+            OperandType::Imm16 => "imm16",
+            OperandType::Imm32 => "imm32",
+            OperandType::Imm64 => "imm64",
+            OperandType::SImm8 => "imm8",
+            OperandType::SImm32 => "imm32",
+            OperandType::Rm8 => "rm8",
+            OperandType::Rm16 => "rm16",
+            OperandType::Rm32 => "rm32",
+            OperandType::Rm64 => "rm64",
+        }
+    }
+
+    /// If self is an immediate or a relative operand
+    pub const fn is_immediate(self) -> bool {
+        matches!(
+            self,
+            OperandType::Rel8
+                | OperandType::Rel16
+                | OperandType::Rel32
+                | OperandType::Imm8
+                | OperandType::Imm16
+                | OperandType::Imm32
+                | OperandType::Imm64
+                | OperandType::SImm8
+                | OperandType::SImm32
+        )
+    }
+
+    /// If self is a relative operand
+    pub const fn is_relative(self) -> bool {
+        matches!(
+            self,
+            OperandType::Rel8 | OperandType::Rel16 | OperandType::Rel32
+        )
+    }
+
+    /// If self is an immediate or a relative operand which must be in signed range
+    pub const fn is_signed(self) -> bool {
+        matches!(self, OperandType::SImm8 | OperandType::SImm32) || self.is_relative()
+    }
+
     /// If self is match with expr
     pub fn match_with(self, expr: &str) -> bool {
         match self {
@@ -267,58 +335,43 @@ impl OperandType {
             OperandType::Ax => expr == "ax",
             OperandType::Eax => expr == "eax",
             OperandType::Rax => expr == "rax",
-            OperandType::Rel8 => {
-                number_match_with(expr, i8::MIN as i128, i8::MAX as i128) || is_keyword(expr)
-            }
-            OperandType::Rel16 => {
-                number_match_with(expr, i16::MIN as i128, i16::MAX as i128) || is_keyword(expr)
-            }
-            OperandType::Rel32 => {
-                number_match_with(expr, i32::MIN as i128, i32::MAX as i128) || is_keyword(expr)
-            }
+            OperandType::Cl => expr == "cl",
+            OperandType::One => number_match_with(expr, 1, 1),
+            OperandType::Rel8 => relocation_match_with(expr, i8::MIN as i128, i8::MAX as i128),
+            OperandType::Rel16 => relocation_match_with(expr, i16::MIN as i128, i16::MAX as i128),
+            OperandType::Rel32 => relocation_match_with(expr, i32::MIN as i128, i32::MAX as i128),
             OperandType::R8 => register_match_with(expr, Register::operand_r8),
             OperandType::R16 => register_match_with(expr, Register::operand_r16),
             OperandType::R32 => register_match_with(expr, Register::operand_r32),
             OperandType::R64 => register_match_with(expr, Register::operand_r64),
             OperandType::Imm8 => number_match_with(expr, i8::MIN as i128, u8::MAX as i128),
             OperandType::Imm16 => number_match_with(expr, i16::MIN as i128, u16::MAX as i128),
-            OperandType::Imm32 => number_match_with(expr, i32::MIN as i128, u32::MAX as i128),
-            OperandType::Imm64 => number_match_with(expr, i64::MIN as i128, u64::MAX as i128),
-            OperandType::Rm8 => rm_match_with(
-                expr,
-                Register::operand_r8,
-                i8::MIN as i128,
-                i8::MAX as i128,
-                'b',
-            ),
-            OperandType::Rm16 => rm_match_with(
-                expr,
-                Register::operand_r16,
-                i16::MIN as i128,
-                i16::MAX as i128,
-                'w',
-            ),
-            OperandType::Rm32 => rm_match_with(
-                expr,
-                Register::operand_r32,
-                i32::MIN as i128,
-                i32::MAX as i128,
-                'd',
-            ),
-            OperandType::Rm64 => rm_match_with(
-                expr,
-                Register::operand_r64,
-                i64::MIN as i128,
-                i64::MAX as i128,
-                'q',
-            ),
+            OperandType::Imm32 => relocation_match_with(expr, i32::MIN as i128, u32::MAX as i128),
+            OperandType::Imm64 => relocation_match_with(expr, i64::MIN as i128, u64::MAX as i128),
+            OperandType::SImm8 => number_match_with(expr, i8::MIN as i128, i8::MAX as i128),
+            OperandType::SImm32 => relocation_match_with(expr, i32::MIN as i128, i32::MAX as i128),
+            OperandType::Rm8 => rm_match_with(expr, Register::operand_r8, 'b'),
+            OperandType::Rm16 => rm_match_with(expr, Register::operand_r16, 'w'),
+            OperandType::Rm32 => rm_match_with(expr, Register::operand_r32, 'd'),
+            OperandType::Rm64 => rm_match_with(expr, Register::operand_r64, 'q'),
         }
     }
 }
 
 fn number_match_with(expr: &str, min: i128, max: i128) -> bool {
-    let value = stoi(expr);
-    value.is_some() && min <= value.expect("unknown error") && value.expect("unknown error") <= max
+    match Relocation::parse(expr) {
+        Some(Relocation::Value(value)) => min <= value && value <= max,
+        _ => false,
+    }
+}
+
+/// Numbers in range and expressions which refer labels are matched
+fn relocation_match_with(expr: &str, min: i128, max: i128) -> bool {
+    match Relocation::parse(expr) {
+        Some(Relocation::Value(value)) => min <= value && value <= max,
+        Some(_) => true,
+        None => false,
+    }
 }
 
 fn register_match_with(expr: &str, matching: impl Fn(Register) -> bool) -> bool {
@@ -330,11 +383,10 @@ fn register_match_with(expr: &str, matching: impl Fn(Register) -> bool) -> bool
     }
 }
 
+/// Displacements are disp8 or disp32 whatever the operand size is, and parse_rm checks their range
 fn rm_match_with(
     expr: &str,
     register_matching: impl Fn(Register) -> bool,
-    disp_min: i128,
-    disp_max: i128,
     address_size_matching: char,
 ) -> bool {
     const fn is_valid_scale(scale: u8) -> bool {
@@ -345,18 +397,17 @@ fn rm_match_with(
         true
     } else {
         match parse_rm(expr.trim(), address_size_matching) {
-            Some((disp, base, optional_index)) => {
-                let base_match = base.operand_rm_ref_base() || base == Register::Rip;
+            Some((_, base, optional_index)) => {
+                let base_match = base.is_none_or(Register::operand_rm_ref_base);
                 let index_match = match optional_index {
-                    Some((index, scale)) => index.operand_rm_ref_index() && is_valid_scale(scale),
-                    None => true,
-                };
-                let disp_match = if let Relocation::Value(d) = disp {
-                    disp_min <= d as i128 && d as i128 <= disp_max
-                } else {
-                    true
+                    Some((index, scale)) => {
+                        index.operand_rm_ref_index()
+                            && is_valid_scale(scale)
+                            && base != Some(Register::Rip)
+                    }
+                    None => base.is_some(),
                 };
-                base_match && index_match && disp_match
+                base_match && index_match
             }
             None => false,
         }
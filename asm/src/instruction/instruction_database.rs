@@ -1,8 +1,12 @@
 use super::*;
 
 /// Instruction list
+/// The first instruction which matches wins, so shorter forms like sign-extended imm8 come first
 pub static INSTRUCTION_LIST: &[Instruction] = &[
     ADC_AL_IMM8,
+    ADC_RM16_IMM8,
+    ADC_RM32_IMM8,
+    ADC_RM64_IMM8,
     ADC_AX_IMM16,
     ADC_EAX_IMM32,
     ADC_RAX_IMM32,
@@ -10,9 +14,6 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     ADC_RM16_IMM16,
     ADC_RM32_IMM32,
     ADC_RM64_IMM32,
-    ADC_RM16_IMM8,
-    ADC_RM32_IMM8,
-    ADC_RM64_IMM8,
     ADC_RM8_REG8,
     ADC_RM16_REG16,
     ADC_RM32_REG32,
@@ -22,6 +23,9 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     ADC_REG32_RM32,
     ADC_REG64_RM64,
     ADD_AL_IMM8,
+    ADD_RM16_IMM8,
+    ADD_RM32_IMM8,
+    ADD_RM64_IMM8,
     ADD_AX_IMM16,
     ADD_EAX_IMM32,
     ADD_RAX_IMM32,
@@ -29,9 +33,6 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     ADD_RM16_IMM16,
     ADD_RM32_IMM32,
     ADD_RM64_IMM32,
-    ADD_RM16_IMM8,
-    ADD_RM32_IMM8,
-    ADD_RM64_IMM8,
     ADD_RM8_REG8,
     ADD_RM16_REG16,
     ADD_RM32_REG32,
@@ -41,6 +42,9 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     ADD_REG32_RM32,
     ADD_REG64_RM64,
     AND_AL_IMM8,
+    AND_RM16_IMM8,
+    AND_RM32_IMM8,
+    AND_RM64_IMM8,
     AND_AX_IMM16,
     AND_EAX_IMM32,
     AND_RAX_IMM32,
@@ -48,9 +52,6 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     AND_RM16_IMM16,
     AND_RM32_IMM32,
     AND_RM64_IMM32,
-    AND_RM16_IMM8,
-    AND_RM32_IMM8,
-    AND_RM64_IMM8,
     AND_RM8_REG8,
     AND_RM16_REG16,
     AND_RM32_REG32,
@@ -68,7 +69,100 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     NEAR_CALL_REL32,
     NEAR_CALL_RM64,
     CLD,
+    CMOVO_REG16_RM16,
+    CMOVO_REG32_RM32,
+    CMOVO_REG64_RM64,
+    CMOVNO_REG16_RM16,
+    CMOVNO_REG32_RM32,
+    CMOVNO_REG64_RM64,
+    CMOVB_REG16_RM16,
+    CMOVB_REG32_RM32,
+    CMOVB_REG64_RM64,
+    CMOVC_REG16_RM16,
+    CMOVC_REG32_RM32,
+    CMOVC_REG64_RM64,
+    CMOVNAE_REG16_RM16,
+    CMOVNAE_REG32_RM32,
+    CMOVNAE_REG64_RM64,
+    CMOVAE_REG16_RM16,
+    CMOVAE_REG32_RM32,
+    CMOVAE_REG64_RM64,
+    CMOVNB_REG16_RM16,
+    CMOVNB_REG32_RM32,
+    CMOVNB_REG64_RM64,
+    CMOVNC_REG16_RM16,
+    CMOVNC_REG32_RM32,
+    CMOVNC_REG64_RM64,
+    CMOVE_REG16_RM16,
+    CMOVE_REG32_RM32,
+    CMOVE_REG64_RM64,
+    CMOVZ_REG16_RM16,
+    CMOVZ_REG32_RM32,
+    CMOVZ_REG64_RM64,
+    CMOVNE_REG16_RM16,
+    CMOVNE_REG32_RM32,
+    CMOVNE_REG64_RM64,
+    CMOVNZ_REG16_RM16,
+    CMOVNZ_REG32_RM32,
+    CMOVNZ_REG64_RM64,
+    CMOVBE_REG16_RM16,
+    CMOVBE_REG32_RM32,
+    CMOVBE_REG64_RM64,
+    CMOVNA_REG16_RM16,
+    CMOVNA_REG32_RM32,
+    CMOVNA_REG64_RM64,
+    CMOVA_REG16_RM16,
+    CMOVA_REG32_RM32,
+    CMOVA_REG64_RM64,
+    CMOVNBE_REG16_RM16,
+    CMOVNBE_REG32_RM32,
+    CMOVNBE_REG64_RM64,
+    CMOVS_REG16_RM16,
+    CMOVS_REG32_RM32,
+    CMOVS_REG64_RM64,
+    CMOVNS_REG16_RM16,
+    CMOVNS_REG32_RM32,
+    CMOVNS_REG64_RM64,
+    CMOVP_REG16_RM16,
+    CMOVP_REG32_RM32,
+    CMOVP_REG64_RM64,
+    CMOVPE_REG16_RM16,
+    CMOVPE_REG32_RM32,
+    CMOVPE_REG64_RM64,
+    CMOVNP_REG16_RM16,
+    CMOVNP_REG32_RM32,
+    CMOVNP_REG64_RM64,
+    CMOVPO_REG16_RM16,
+    CMOVPO_REG32_RM32,
+    CMOVPO_REG64_RM64,
+    CMOVL_REG16_RM16,
+    CMOVL_REG32_RM32,
+    CMOVL_REG64_RM64,
+    CMOVNGE_REG16_RM16,
+    CMOVNGE_REG32_RM32,
+    CMOVNGE_REG64_RM64,
+    CMOVGE_REG16_RM16,
+    CMOVGE_REG32_RM32,
+    CMOVGE_REG64_RM64,
+    CMOVNL_REG16_RM16,
+    CMOVNL_REG32_RM32,
+    CMOVNL_REG64_RM64,
+    CMOVLE_REG16_RM16,
+    CMOVLE_REG32_RM32,
+    CMOVLE_REG64_RM64,
+    CMOVNG_REG16_RM16,
+    CMOVNG_REG32_RM32,
+    CMOVNG_REG64_RM64,
+    CMOVG_REG16_RM16,
+    CMOVG_REG32_RM32,
+    CMOVG_REG64_RM64,
+    CMOVNLE_REG16_RM16,
+    CMOVNLE_REG32_RM32,
+    CMOVNLE_REG64_RM64,
     CMP_AL_IMM8,
+    CMP_RM16_IMM8,
+    CMP_RM32_IMM8,
+    CMP_RM64_IMM8,
     CMP_AX_IMM16,
     CMP_EAX_IMM32,
     CMP_RAX_IMM32,
@@ -76,9 +170,6 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     CMP_RM16_IMM16,
     CMP_RM32_IMM32,
     CMP_RM64_IMM32,
-    CMP_RM16_IMM8,
-    CMP_RM32_IMM8,
-    CMP_RM64_IMM8,
     CMP_RM8_R8,
     CMP_RM16_R16,
     CMP_RM32_R32,
@@ -103,6 +194,154 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     IDIV_RM16,
     IDIV_RM32,
     IDIV_RM64,
+    INC_RM8,
+    INC_RM16,
+    INC_RM32,
+    INC_RM64,
+    JO_REL32,
+    JO_REL8,
+    JNO_REL32,
+    JNO_REL8,
+    JB_REL32,
+    JB_REL8,
+    JC_REL32,
+    JC_REL8,
+    JNAE_REL32,
+    JNAE_REL8,
+    JAE_REL32,
+    JAE_REL8,
+    JNB_REL32,
+    JNB_REL8,
+    JNC_REL32,
+    JNC_REL8,
+    JE_REL32,
+    JE_REL8,
+    JZ_REL32,
+    JZ_REL8,
+    JNE_REL32,
+    JNE_REL8,
+    JNZ_REL32,
+    JNZ_REL8,
+    JBE_REL32,
+    JBE_REL8,
+    JNA_REL32,
+    JNA_REL8,
+    JA_REL32,
+    JA_REL8,
+    JNBE_REL32,
+    JNBE_REL8,
+    JS_REL32,
+    JS_REL8,
+    JNS_REL32,
+    JNS_REL8,
+    JP_REL32,
+    JP_REL8,
+    JPE_REL32,
+    JPE_REL8,
+    JNP_REL32,
+    JNP_REL8,
+    JPO_REL32,
+    JPO_REL8,
+    JL_REL32,
+    JL_REL8,
+    JNGE_REL32,
+    JNGE_REL8,
+    JGE_REL32,
+    JGE_REL8,
+    JNL_REL32,
+    JNL_REL8,
+    JLE_REL32,
+    JLE_REL8,
+    JNG_REL32,
+    JNG_REL8,
+    JG_REL32,
+    JG_REL8,
+    JNLE_REL32,
+    JNLE_REL8,
+    NEAR_JMP_REL32,
+    SHORT_JMP_REL8,
+    NEAR_JMP_RM64,
+    JRCXZ_REL8,
+    LOOP_REL8,
+    LOOPE_REL8,
+    LOOPZ_REL8,
+    LOOPNE_REL8,
+    LOOPNZ_REL8,
+    NEG_RM8,
+    NEG_RM16,
+    NEG_RM32,
+    NEG_RM64,
+    NOT_RM8,
+    NOT_RM16,
+    NOT_RM32,
+    NOT_RM64,
+    OR_AL_IMM8,
+    OR_RM16_IMM8,
+    OR_RM32_IMM8,
+    OR_RM64_IMM8,
+    OR_AX_IMM16,
+    OR_EAX_IMM32,
+    OR_RAX_IMM32,
+    OR_RM8_IMM8,
+    OR_RM16_IMM16,
+    OR_RM32_IMM32,
+    OR_RM64_IMM32,
+    OR_RM8_REG8,
+    OR_RM16_REG16,
+    OR_RM32_REG32,
+    OR_RM64_REG64,
+    OR_REG8_RM8,
+    OR_REG16_RM16,
+    OR_REG32_RM32,
+    OR_REG64_RM64,
+    RCL_RM8_ONE,
+    RCL_RM8_CL,
+    RCL_RM8_IMM8,
+    RCL_RM16_ONE,
+    RCL_RM16_CL,
+    RCL_RM16_IMM8,
+    RCL_RM32_ONE,
+    RCL_RM32_CL,
+    RCL_RM32_IMM8,
+    RCL_RM64_ONE,
+    RCL_RM64_CL,
+    RCL_RM64_IMM8,
+    RCR_RM8_ONE,
+    RCR_RM8_CL,
+    RCR_RM8_IMM8,
+    RCR_RM16_ONE,
+    RCR_RM16_CL,
+    RCR_RM16_IMM8,
+    RCR_RM32_ONE,
+    RCR_RM32_CL,
+    RCR_RM32_IMM8,
+    RCR_RM64_ONE,
+    RCR_RM64_CL,
+    RCR_RM64_IMM8,
+    ROL_RM8_ONE,
+    ROL_RM8_CL,
+    ROL_RM8_IMM8,
+    ROL_RM16_ONE,
+    ROL_RM16_CL,
+    ROL_RM16_IMM8,
+    ROL_RM32_ONE,
+    ROL_RM32_CL,
+    ROL_RM32_IMM8,
+    ROL_RM64_ONE,
+    ROL_RM64_CL,
+    ROL_RM64_IMM8,
+    ROR_RM8_ONE,
+    ROR_RM8_CL,
+    ROR_RM8_IMM8,
+    ROR_RM16_ONE,
+    ROR_RM16_CL,
+    ROR_RM16_IMM8,
+    ROR_RM32_ONE,
+    ROR_RM32_CL,
+    ROR_RM32_IMM8,
+    ROR_RM64_ONE,
+    ROR_RM64_CL,
+    ROR_RM64_IMM8,
     PUSH_R64,
     PUSH_RM64,
     PUSH_IMM64,
@@ -112,6 +351,165 @@ pub static INSTRUCTION_LIST: &[Instruction] = &[
     MOV_R32_IMM32,
     POP_R64,
     NEAR_RET,
+    SETO_RM8,
+    SETNO_RM8,
+    SETB_RM8,
+    SETC_RM8,
+    SETNAE_RM8,
+    SETAE_RM8,
+    SETNB_RM8,
+    SETNC_RM8,
+    SETE_RM8,
+    SETZ_RM8,
+    SETNE_RM8,
+    SETNZ_RM8,
+    SETBE_RM8,
+    SETNA_RM8,
+    SETA_RM8,
+    SETNBE_RM8,
+    SETS_RM8,
+    SETNS_RM8,
+    SETP_RM8,
+    SETPE_RM8,
+    SETNP_RM8,
+    SETPO_RM8,
+    SETL_RM8,
+    SETNGE_RM8,
+    SETGE_RM8,
+    SETNL_RM8,
+    SETLE_RM8,
+    SETNG_RM8,
+    SETG_RM8,
+    SETNLE_RM8,
+    SBB_AL_IMM8,
+    SBB_RM16_IMM8,
+    SBB_RM32_IMM8,
+    SBB_RM64_IMM8,
+    SBB_AX_IMM16,
+    SBB_EAX_IMM32,
+    SBB_RAX_IMM32,
+    SBB_RM8_IMM8,
+    SBB_RM16_IMM16,
+    SBB_RM32_IMM32,
+    SBB_RM64_IMM32,
+    SBB_RM8_REG8,
+    SBB_RM16_REG16,
+    SBB_RM32_REG32,
+    SBB_RM64_REG64,
+    SBB_REG8_RM8,
+    SBB_REG16_RM16,
+    SBB_REG32_RM32,
+    SBB_REG64_RM64,
+    SAL_RM8_ONE,
+    SAL_RM8_CL,
+    SAL_RM8_IMM8,
+    SAL_RM16_ONE,
+    SAL_RM16_CL,
+    SAL_RM16_IMM8,
+    SAL_RM32_ONE,
+    SAL_RM32_CL,
+    SAL_RM32_IMM8,
+    SAL_RM64_ONE,
+    SAL_RM64_CL,
+    SAL_RM64_IMM8,
+    SAR_RM8_ONE,
+    SAR_RM8_CL,
+    SAR_RM8_IMM8,
+    SAR_RM16_ONE,
+    SAR_RM16_CL,
+    SAR_RM16_IMM8,
+    SAR_RM32_ONE,
+    SAR_RM32_CL,
+    SAR_RM32_IMM8,
+    SAR_RM64_ONE,
+    SAR_RM64_CL,
+    SAR_RM64_IMM8,
+    SHL_RM8_ONE,
+    SHL_RM8_CL,
+    SHL_RM8_IMM8,
+    SHL_RM16_ONE,
+    SHL_RM16_CL,
+    SHL_RM16_IMM8,
+    SHL_RM32_ONE,
+    SHL_RM32_CL,
+    SHL_RM32_IMM8,
+    SHL_RM64_ONE,
+    SHL_RM64_CL,
+    SHL_RM64_IMM8,
+    SHLD_RM16_REG16_IMM8,
+    SHLD_RM16_REG16_CL,
+    SHLD_RM32_REG32_IMM8,
+    SHLD_RM32_REG32_CL,
+    SHLD_RM64_REG64_IMM8,
+    SHLD_RM64_REG64_CL,
+    SHR_RM8_ONE,
+    SHR_RM8_CL,
+    SHR_RM8_IMM8,
+    SHR_RM16_ONE,
+    SHR_RM16_CL,
+    SHR_RM16_IMM8,
+    SHR_RM32_ONE,
+    SHR_RM32_CL,
+    SHR_RM32_IMM8,
+    SHR_RM64_ONE,
+    SHR_RM64_CL,
+    SHR_RM64_IMM8,
+    SHRD_RM16_REG16_IMM8,
+    SHRD_RM16_REG16_CL,
+    SHRD_RM32_REG32_IMM8,
+    SHRD_RM32_REG32_CL,
+    SHRD_RM64_REG64_IMM8,
+    SHRD_RM64_REG64_CL,
+    SUB_AL_IMM8,
+    SUB_RM16_IMM8,
+    SUB_RM32_IMM8,
+    SUB_RM64_IMM8,
+    SUB_AX_IMM16,
+    SUB_EAX_IMM32,
+    SUB_RAX_IMM32,
+    SUB_RM8_IMM8,
+    SUB_RM16_IMM16,
+    SUB_RM32_IMM32,
+    SUB_RM64_IMM32,
+    SUB_RM8_REG8,
+    SUB_RM16_REG16,
+    SUB_RM32_REG32,
+    SUB_RM64_REG64,
+    SUB_REG8_RM8,
+    SUB_REG16_RM16,
+    SUB_REG32_RM32,
+    SUB_REG64_RM64,
+    TEST_AL_IMM8,
+    TEST_AX_IMM16,
+    TEST_EAX_IMM32,
+    TEST_RAX_IMM32,
+    TEST_RM8_IMM8,
+    TEST_RM16_IMM16,
+    TEST_RM32_IMM32,
+    TEST_RM64_IMM32,
+    TEST_RM8_REG8,
+    TEST_RM16_REG16,
+    TEST_RM32_REG32,
+    TEST_RM64_REG64,
+    XOR_AL_IMM8,
+    XOR_RM16_IMM8,
+    XOR_RM32_IMM8,
+    XOR_RM64_IMM8,
+    XOR_AX_IMM16,
+    XOR_EAX_IMM32,
+    XOR_RAX_IMM32,
+    XOR_RM8_IMM8,
+    XOR_RM16_IMM16,
+    XOR_RM32_IMM32,
+    XOR_RM64_IMM32,
+    XOR_RM8_REG8,
+    XOR_RM16_REG16,
+    XOR_RM32_REG32,
+    XOR_RM64_REG64,
+    XOR_REG8_RM8,
+    XOR_REG16_RM16,
+    XOR_REG32_RM32,
+    XOR_REG64_RM64,
 ];
 
 /// ADC AL, imm8    14 ib
@@ -125,7 +523,7 @@ const ADC_AL_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Al), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
     },
 };
 
@@ -140,7 +538,7 @@ const ADC_AX_IMM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Ax), Some(OperandType::Imm16)],
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
     },
 };
 
@@ -155,7 +553,7 @@ const ADC_EAX_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Eax), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
     },
 };
 
@@ -170,7 +568,7 @@ const ADC_RAX_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rax), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
     },
 };
 
@@ -185,7 +583,7 @@ const ADC_RM8_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
     },
 };
 
@@ -200,7 +598,7 @@ const ADC_RM16_IMM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
     },
 };
 
@@ -215,7 +613,7 @@ const ADC_RM32_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
     },
 };
 
@@ -230,7 +628,7 @@ const ADC_RM64_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
     },
 };
 
@@ -245,7 +643,7 @@ const ADC_RM16_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
     },
 };
 
@@ -260,7 +658,7 @@ const ADC_RM32_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
     },
 };
 
@@ -275,7 +673,7 @@ const ADC_RM64_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
     },
 };
 
@@ -290,7 +688,7 @@ const ADC_RM8_REG8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm8), Some(OperandType::R8)],
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
     },
 };
 
@@ -305,7 +703,7 @@ const ADC_RM16_REG16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm16), Some(OperandType::R16)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
     },
 };
 
@@ -320,7 +718,7 @@ const ADC_RM32_REG32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm32), Some(OperandType::R32)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
     },
 };
 
@@ -335,7 +733,7 @@ const ADC_RM64_REG64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::Rm64), Some(OperandType::R64)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
     },
 };
 
@@ -350,7 +748,7 @@ const ADC_REG8_RM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::R8), Some(OperandType::Rm8)],
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
     },
 };
 
@@ -365,7 +763,7 @@ const ADC_REG16_RM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::R16), Some(OperandType::Rm16)],
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
@@ -380,7 +778,7 @@ const ADC_REG32_RM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::R32), Some(OperandType::Rm32)],
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
@@ -395,7 +793,7 @@ const ADC_REG64_RM64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "adc",
-        operands: [Some(OperandType::R64), Some(OperandType::Rm64)],
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
@@ -410,7 +808,7 @@ const ADD_AL_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Al), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
     },
 };
 
@@ -425,7 +823,7 @@ const ADD_AX_IMM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Ax), Some(OperandType::Imm16)],
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
     },
 };
 
@@ -440,7 +838,7 @@ const ADD_EAX_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Eax), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
     },
 };
 
@@ -455,7 +853,7 @@ const ADD_RAX_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rax), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
     },
 };
 
@@ -470,7 +868,7 @@ const ADD_RM8_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
     },
 };
 
@@ -485,7 +883,7 @@ const ADD_RM16_IMM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
     },
 };
 
@@ -500,7 +898,7 @@ const ADD_RM32_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
     },
 };
 
@@ -515,7 +913,7 @@ const ADD_RM64_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
     },
 };
 
@@ -530,7 +928,7 @@ const ADD_RM16_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
     },
 };
 
@@ -545,7 +943,7 @@ const ADD_RM32_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
     },
 };
 
@@ -560,7 +958,7 @@ const ADD_RM64_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
     },
 };
 
@@ -575,7 +973,7 @@ const ADD_RM8_REG8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm8), Some(OperandType::R8)],
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
     },
 };
 
@@ -590,7 +988,7 @@ const ADD_RM16_REG16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm16), Some(OperandType::R16)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
     },
 };
 
@@ -605,7 +1003,7 @@ const ADD_RM32_REG32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm32), Some(OperandType::R32)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
     },
 };
 
@@ -620,7 +1018,7 @@ const ADD_RM64_REG64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::Rm64), Some(OperandType::R64)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
     },
 };
 
@@ -635,7 +1033,7 @@ const ADD_REG8_RM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::R8), Some(OperandType::Rm8)],
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
     },
 };
 
@@ -650,7 +1048,7 @@ const ADD_REG16_RM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::R16), Some(OperandType::Rm16)],
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
@@ -665,7 +1063,7 @@ const ADD_REG32_RM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::R32), Some(OperandType::Rm32)],
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
@@ -680,7 +1078,7 @@ const ADD_REG64_RM64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "add",
-        operands: [Some(OperandType::R64), Some(OperandType::Rm64)],
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
@@ -695,7 +1093,7 @@ const AND_AL_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Al), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
     },
 };
 
@@ -710,7 +1108,7 @@ const AND_AX_IMM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Ax), Some(OperandType::Imm16)],
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
     },
 };
 
@@ -725,7 +1123,7 @@ const AND_EAX_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Eax), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
     },
 };
 
@@ -740,7 +1138,7 @@ const AND_RAX_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rax), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
     },
 };
 
@@ -755,7 +1153,7 @@ const AND_RM8_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
     },
 };
 
@@ -770,7 +1168,7 @@ const AND_RM16_IMM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
     },
 };
 
@@ -785,7 +1183,7 @@ const AND_RM32_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
     },
 };
 
@@ -800,7 +1198,7 @@ const AND_RM64_IMM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm32)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
     },
 };
 
@@ -815,7 +1213,7 @@ const AND_RM16_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
     },
 };
 
@@ -830,7 +1228,7 @@ const AND_RM32_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
     },
 };
 
@@ -845,7 +1243,7 @@ const AND_RM64_IMM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
     },
 };
 
@@ -860,7 +1258,7 @@ const AND_RM8_REG8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm8), Some(OperandType::R8)],
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
     },
 };
 
@@ -875,7 +1273,7 @@ const AND_RM16_REG16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm16), Some(OperandType::R16)],
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
     },
 };
 
@@ -890,7 +1288,7 @@ const AND_RM32_REG32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm32), Some(OperandType::R32)],
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
     },
 };
 
@@ -905,7 +1303,7 @@ const AND_RM64_REG64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::Rm64), Some(OperandType::R64)],
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
     },
 };
 
@@ -920,7 +1318,7 @@ const AND_REG8_RM8: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::R8), Some(OperandType::Rm8)],
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
     },
 };
 
@@ -935,7 +1333,7 @@ const AND_REG16_RM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::R16), Some(OperandType::Rm16)],
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
@@ -950,7 +1348,7 @@ const AND_REG32_RM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::R32), Some(OperandType::Rm32)],
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
@@ -965,7 +1363,7 @@ const AND_REG64_RM64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "and",
-        operands: [Some(OperandType::R64), Some(OperandType::Rm64)],
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
@@ -980,7 +1378,7 @@ const BSF_REG16_RM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "bsf",
-        operands: [Some(OperandType::R16), Some(OperandType::Rm16)],
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
@@ -995,7 +1393,7 @@ const BSF_REG32_RM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "bsf",
-        operands: [Some(OperandType::R32), Some(OperandType::Rm32)],
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
@@ -1010,7 +1408,7 @@ const BSF_REG64_RM64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "bsf",
-        operands: [Some(OperandType::R64), Some(OperandType::Rm64)],
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
@@ -1025,7 +1423,7 @@ const BSR_REG16_RM16: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "bsr",
-        operands: [Some(OperandType::R16), Some(OperandType::Rm16)],
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
@@ -1040,7 +1438,7 @@ const BSR_REG32_RM32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "bsr",
-        operands: [Some(OperandType::R32), Some(OperandType::Rm32)],
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
@@ -1055,7 +1453,7 @@ const BSR_REG64_RM64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "bsr",
-        operands: [Some(OperandType::R64), Some(OperandType::Rm64)],
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
@@ -1070,7 +1468,7 @@ const NEAR_CALL_REL32: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "call",
-        operands: [Some(OperandType::Rel32), None],
+        operands: [Some(OperandType::Rel32), None, None],
     },
 };
 
@@ -1085,7 +1483,7 @@ const NEAR_CALL_RM64: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "call",
-        operands: [Some(OperandType::Rm64), None],
+        operands: [Some(OperandType::Rm64), None, None],
     },
 };
 
@@ -1100,541 +1498,1889 @@ const CLD: Instruction = Instruction {
     },
     expression: Expression {
         mnemonic: "cld",
-        operands: [None, None],
+        operands: [None, None, None],
     },
 };
 
-// CMP AL, imm83C ib
-const CMP_AL_IMM8: Instruction = Instruction {
+// CMOVO reg16, reg/mem16    0F 40 /r    Move if overflow (OF=1).
+const CMOVO_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3c, 0x00, 0x00], 1),
-        modrm: None,
-        imm: Some(ImmRule::Ib),
+        opecode: SVec::from_raw([0x0f, 0x40, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Al), Some(OperandType::Imm8)],
+        mnemonic: "cmovo",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// CMP AX, imm163D iw
-const CMP_AX_IMM16: Instruction = Instruction {
+// CMOVO reg32, reg/mem32    0F 40 /r    Move if overflow (OF=1).
+const CMOVO_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3d, 0x00, 0x00], 1),
-        modrm: None,
-        imm: Some(ImmRule::Iw),
+        opecode: SVec::from_raw([0x0f, 0x40, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Ax), Some(OperandType::Imm16)],
+        mnemonic: "cmovo",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// CMP EAX, imm323D id
-const CMP_EAX_IMM32: Instruction = Instruction {
+// CMOVO reg64, reg/mem64    0F 40 /r    Move if overflow (OF=1).
+const CMOVO_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3d, 0x00, 0x00], 1),
-        modrm: None,
-        imm: Some(ImmRule::Id),
+        opecode: SVec::from_raw([0x0f, 0x40, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Eax), Some(OperandType::Imm32)],
+        mnemonic: "cmovo",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// CMP RAX, imm323D id
-const CMP_RAX_IMM32: Instruction = Instruction {
+// CMOVNO reg16, reg/mem16    0F 41 /r    Move if not overflow (OF=0).
+const CMOVNO_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3d, 0x00, 0x00], 1),
-        modrm: None,
-        imm: Some(ImmRule::Id),
+        opecode: SVec::from_raw([0x0f, 0x41, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rax), Some(OperandType::Imm32)],
+        mnemonic: "cmovno",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// CMP reg/mem8, imm880 /7 ib
-const CMP_RM8_IMM8: Instruction = Instruction {
+// CMOVNO reg32, reg/mem32    0F 41 /r    Move if not overflow (OF=0).
+const CMOVNO_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x80, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
-        imm: Some(ImmRule::Ib),
+        opecode: SVec::from_raw([0x0f, 0x41, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8)],
+        mnemonic: "cmovno",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// CMP reg/mem16, imm1681 /7 iw
-const CMP_RM16_IMM16: Instruction = Instruction {
+// CMOVNO reg64, reg/mem64    0F 41 /r    Move if not overflow (OF=0).
+const CMOVNO_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
-        imm: Some(ImmRule::Iw),
+        opecode: SVec::from_raw([0x0f, 0x41, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16)],
+        mnemonic: "cmovno",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// CMP reg/mem32, imm3281 /7 id
-const CMP_RM32_IMM32: Instruction = Instruction {
+// CMOVB reg16, reg/mem16    0F 42 /r    Move if below (CF=1).
+const CMOVB_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
-        imm: Some(ImmRule::Id),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32)],
+        mnemonic: "cmovb",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// CMP reg/mem64, imm3281 /7 id
-const CMP_RM64_IMM32: Instruction = Instruction {
+// CMOVB reg32, reg/mem32    0F 42 /r    Move if below (CF=1).
+const CMOVB_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
-        imm: Some(ImmRule::Id),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm32)],
+        mnemonic: "cmovb",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// CMP reg/mem16, imm883 /7 ib
-const CMP_RM16_IMM8: Instruction = Instruction {
+// CMOVB reg64, reg/mem64    0F 42 /r    Move if below (CF=1).
+const CMOVB_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
-        imm: Some(ImmRule::Ib),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8)],
+        mnemonic: "cmovb",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// CMP reg/mem32, imm883 /7 ib
-const CMP_RM32_IMM8: Instruction = Instruction {
+// CMOVC reg16, reg/mem16    0F 42 /r    Move if carry (CF=1).
+const CMOVC_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
-        imm: Some(ImmRule::Ib),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8)],
+        mnemonic: "cmovc",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// CMP reg/mem64, imm883 /7 ib
-const CMP_RM64_IMM8: Instruction = Instruction {
+// CMOVC reg32, reg/mem32    0F 42 /r    Move if carry (CF=1).
+const CMOVC_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
-        imm: Some(ImmRule::Ib),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8)],
+        mnemonic: "cmovc",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// CMP reg/mem8, reg838 /r
-const CMP_RM8_R8: Instruction = Instruction {
+// CMOVC reg64, reg/mem64    0F 42 /r    Move if carry (CF=1).
+const CMOVC_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x38, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm8), Some(OperandType::R8)],
+        mnemonic: "cmovc",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// CMP reg/mem16, reg1639 /r
-const CMP_RM16_R16: Instruction = Instruction {
+// CMOVNAE reg16, reg/mem16    0F 42 /r    Move if not above or equal (CF=1).
+const CMOVNAE_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x39, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm16), Some(OperandType::R16)],
+        mnemonic: "cmovnae",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// CMP reg/mem32, reg3239 /r
-const CMP_RM32_R32: Instruction = Instruction {
+// CMOVNAE reg32, reg/mem32    0F 42 /r    Move if not above or equal (CF=1).
+const CMOVNAE_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x39, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm32), Some(OperandType::R32)],
+        mnemonic: "cmovnae",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// CMP reg/mem64, reg6439 /r
-const CMP_RM64_R64: Instruction = Instruction {
+// CMOVNAE reg64, reg/mem64    0F 42 /r    Move if not above or equal (CF=1).
+const CMOVNAE_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x39, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x42, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::Rm64), Some(OperandType::R64)],
+        mnemonic: "cmovnae",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// CMP reg8, reg/mem83A /r
-const CMP_R8_RM8: Instruction = Instruction {
+// CMOVAE reg16, reg/mem16    0F 43 /r    Move if above or equal (CF=0).
+const CMOVAE_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3a, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::R8), Some(OperandType::Rm8)],
+        mnemonic: "cmovae",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// CMP reg16, reg/mem163B /r
-const CMP_R16_RM16: Instruction = Instruction {
+// CMOVAE reg32, reg/mem32    0F 43 /r    Move if above or equal (CF=0).
+const CMOVAE_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3b, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::R16), Some(OperandType::Rm16)],
+        mnemonic: "cmovae",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// CMP reg32, reg/mem323B /r
-const CMP_R32_RM32: Instruction = Instruction {
+// CMOVAE reg64, reg/mem64    0F 43 /r    Move if above or equal (CF=0).
+const CMOVAE_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3b, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::R32), Some(OperandType::Rm32)],
+        mnemonic: "cmovae",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// CMP reg64, reg/mem643B /r
-const CMP_R64_RM64: Instruction = Instruction {
+// CMOVNB reg16, reg/mem16    0F 43 /r    Move if not below (CF=0).
+const CMOVNB_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x3b, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cmp",
-        operands: [Some(OperandType::R64), Some(OperandType::Rm64)],
+        mnemonic: "cmovnb",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// CPUID    0F A2
-const CPUID: Instruction = Instruction {
+// CMOVNB reg32, reg/mem32    0F 43 /r    Move if not below (CF=0).
+const CMOVNB_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x0f, 0xa2, 0x00], 2),
-        modrm: None,
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cpuid",
-        operands: [None, None],
+        mnemonic: "cmovnb",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// CQO 99
-const CQO: Instruction = Instruction {
+// CMOVNB reg64, reg/mem64    0F 43 /r    Move if not below (CF=0).
+const CMOVNB_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x99, 0x00, 0x00], 1),
-        modrm: None,
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "cqo",
-        operands: [None, None],
+        mnemonic: "cmovnb",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-
-// DEC reg/mem8FE /1
-const DEC_RM8: Instruction = Instruction {
+// CMOVNC reg16, reg/mem16    0F 43 /r    Move if not carry (CF=0).
+const CMOVNC_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xfe, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(1)),
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "dec",
-        operands: [Some(OperandType::Rm8), None],
+        mnemonic: "cmovnc",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// DEC reg/mem16FF /1
-const DEC_RM16: Instruction = Instruction {
+// CMOVNC reg32, reg/mem32    0F 43 /r    Move if not carry (CF=0).
+const CMOVNC_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(1)),
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "dec",
-        operands: [Some(OperandType::Rm16), None],
+        mnemonic: "cmovnc",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// DEC reg/mem32FF /1
-const DEC_RM32: Instruction = Instruction {
+// CMOVNC reg64, reg/mem64    0F 43 /r    Move if not carry (CF=0).
+const CMOVNC_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(1)),
+        opecode: SVec::from_raw([0x0f, 0x43, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "dec",
-        operands: [Some(OperandType::Rm32), None],
+        mnemonic: "cmovnc",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// DEC reg/mem64FF /1
-const DEC_RM64: Instruction = Instruction {
+// CMOVE reg16, reg/mem16    0F 44 /r    Move if equal (ZF=1).
+const CMOVE_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(1)),
+        opecode: SVec::from_raw([0x0f, 0x44, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "dec",
-        operands: [Some(OperandType::Rm64), None],
+        mnemonic: "cmove",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// DEC reg1648 +rw
-const DEC_R16: Instruction = Instruction {
+// CMOVE reg32, reg/mem32    0F 44 /r    Move if equal (ZF=1).
+const CMOVE_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x48, 0x00, 0x00], 1),
-        modrm: None,
+        opecode: SVec::from_raw([0x0f, 0x44, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
-        opecode_register: Some(OpecodeRegisterRule::Rw),
+        opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "dec",
-        operands: [Some(OperandType::R16), None],
+        mnemonic: "cmove",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// DEC reg3248 +rd
-const DEC_R32: Instruction = Instruction {
+// CMOVE reg64, reg/mem64    0F 44 /r    Move if equal (ZF=1).
+const CMOVE_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x48, 0x00, 0x00], 1),
-        modrm: None,
+        opecode: SVec::from_raw([0x0f, 0x44, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
-        opecode_register: Some(OpecodeRegisterRule::Rd),
+        opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "dec",
-        operands: [Some(OperandType::R32), None],
+        mnemonic: "cmove",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// DIV reg/mem8F6 /6
-const DIV_RM8: Instruction = Instruction {
+// CMOVZ reg16, reg/mem16    0F 44 /r    Move if zero (ZF=1).
+const CMOVZ_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf6, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(6)),
+        opecode: SVec::from_raw([0x0f, 0x44, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "div",
-        operands: [Some(OperandType::Rm8), None],
+        mnemonic: "cmovz",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// DIV reg/mem16F7 /6
-const DIV_RM16: Instruction = Instruction {
+// CMOVZ reg32, reg/mem32    0F 44 /r    Move if zero (ZF=1).
+const CMOVZ_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(6)),
+        opecode: SVec::from_raw([0x0f, 0x44, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "div",
-        operands: [Some(OperandType::Rm16), None],
+        mnemonic: "cmovz",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// DIV reg/mem32F7 /6
-const DIV_RM32: Instruction = Instruction {
+// CMOVZ reg64, reg/mem64    0F 44 /r    Move if zero (ZF=1).
+const CMOVZ_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(6)),
+        opecode: SVec::from_raw([0x0f, 0x44, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "div",
-        operands: [Some(OperandType::Rm32), None],
+        mnemonic: "cmovz",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// DIV reg/mem64F7 /6
-const DIV_RM64: Instruction = Instruction {
+// CMOVNE reg16, reg/mem16    0F 45 /r    Move if not equal (ZF=0).
+const CMOVNE_REG16_RM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(6)),
+        opecode: SVec::from_raw([0x0f, 0x45, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "div",
-        operands: [Some(OperandType::Rm64), None],
+        mnemonic: "cmovne",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
     },
 };
 
-// IDIV reg/mem8F6 /7
-const IDIV_RM8: Instruction = Instruction {
+// CMOVNE reg32, reg/mem32    0F 45 /r    Move if not equal (ZF=0).
+const CMOVNE_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf6, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
+        opecode: SVec::from_raw([0x0f, 0x45, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "idiv",
-        operands: [Some(OperandType::Rm8), None],
+        mnemonic: "cmovne",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// IDIV reg/mem16F7 /7
-const IDIV_RM16: Instruction = Instruction {
+// CMOVNE reg64, reg/mem64    0F 45 /r    Move if not equal (ZF=0).
+const CMOVNE_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
+        opecode: SVec::from_raw([0x0f, 0x45, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "idiv",
-        operands: [Some(OperandType::Rm16), None],
+        mnemonic: "cmovne",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
+// CMOVNZ reg16, reg/mem16    0F 45 /r    Move if not zero (ZF=0).
+const CMOVNZ_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x45, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnz",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
 
-// IDIV reg/mem32F7 /7
-const IDIV_RM32: Instruction = Instruction {
+// CMOVNZ reg32, reg/mem32    0F 45 /r    Move if not zero (ZF=0).
+const CMOVNZ_REG32_RM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
+        opecode: SVec::from_raw([0x0f, 0x45, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "idiv",
-        operands: [Some(OperandType::Rm32), None],
+        mnemonic: "cmovnz",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
     },
 };
 
-// IDIV reg/mem64F7 /7
-const IDIV_RM64: Instruction = Instruction {
+// CMOVNZ reg64, reg/mem64    0F 45 /r    Move if not zero (ZF=0).
+const CMOVNZ_REG64_RM64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(7)),
+        opecode: SVec::from_raw([0x0f, 0x45, 0x00], 2),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "idiv",
-        operands: [Some(OperandType::Rm64), None],
+        mnemonic: "cmovnz",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
 
-// IMUL reg/mem8F6 /5
-// IMUL reg/mem16F7 /5
-// IMUL reg/mem32F7 /5
-// IMUL reg/mem64F7 /5
+// CMOVBE reg16, reg/mem16    0F 46 /r    Move if below or equal (CF=1 or ZF=1).
+const CMOVBE_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x46, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovbe",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVBE reg32, reg/mem32    0F 46 /r    Move if below or equal (CF=1 or ZF=1).
+const CMOVBE_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x46, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovbe",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVBE reg64, reg/mem64    0F 46 /r    Move if below or equal (CF=1 or ZF=1).
+const CMOVBE_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x46, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovbe",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNA reg16, reg/mem16    0F 46 /r    Move if not above (CF=1 or ZF=1).
+const CMOVNA_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x46, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovna",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNA reg32, reg/mem32    0F 46 /r    Move if not above (CF=1 or ZF=1).
+const CMOVNA_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x46, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovna",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNA reg64, reg/mem64    0F 46 /r    Move if not above (CF=1 or ZF=1).
+const CMOVNA_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x46, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovna",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVA reg16, reg/mem16    0F 47 /r    Move if above (CF=0 and ZF=0).
+const CMOVA_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x47, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmova",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVA reg32, reg/mem32    0F 47 /r    Move if above (CF=0 and ZF=0).
+const CMOVA_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x47, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmova",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVA reg64, reg/mem64    0F 47 /r    Move if above (CF=0 and ZF=0).
+const CMOVA_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x47, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmova",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNBE reg16, reg/mem16    0F 47 /r    Move if not below or equal (CF=0 and ZF=0).
+const CMOVNBE_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x47, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnbe",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNBE reg32, reg/mem32    0F 47 /r    Move if not below or equal (CF=0 and ZF=0).
+const CMOVNBE_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x47, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnbe",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNBE reg64, reg/mem64    0F 47 /r    Move if not below or equal (CF=0 and ZF=0).
+const CMOVNBE_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x47, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnbe",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVS reg16, reg/mem16    0F 48 /r    Move if sign (SF=1).
+const CMOVS_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x48, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovs",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVS reg32, reg/mem32    0F 48 /r    Move if sign (SF=1).
+const CMOVS_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x48, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovs",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVS reg64, reg/mem64    0F 48 /r    Move if sign (SF=1).
+const CMOVS_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x48, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovs",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNS reg16, reg/mem16    0F 49 /r    Move if not sign (SF=0).
+const CMOVNS_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x49, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovns",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNS reg32, reg/mem32    0F 49 /r    Move if not sign (SF=0).
+const CMOVNS_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x49, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovns",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNS reg64, reg/mem64    0F 49 /r    Move if not sign (SF=0).
+const CMOVNS_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x49, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovns",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVP reg16, reg/mem16    0F 4A /r    Move if parity (PF=1).
+const CMOVP_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4a, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovp",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVP reg32, reg/mem32    0F 4A /r    Move if parity (PF=1).
+const CMOVP_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4a, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovp",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVP reg64, reg/mem64    0F 4A /r    Move if parity (PF=1).
+const CMOVP_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4a, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovp",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVPE reg16, reg/mem16    0F 4A /r    Move if parity even (PF=1).
+const CMOVPE_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4a, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovpe",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVPE reg32, reg/mem32    0F 4A /r    Move if parity even (PF=1).
+const CMOVPE_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4a, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovpe",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVPE reg64, reg/mem64    0F 4A /r    Move if parity even (PF=1).
+const CMOVPE_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4a, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovpe",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNP reg16, reg/mem16    0F 4B /r    Move if not parity (PF=0).
+const CMOVNP_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4b, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnp",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNP reg32, reg/mem32    0F 4B /r    Move if not parity (PF=0).
+const CMOVNP_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4b, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnp",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNP reg64, reg/mem64    0F 4B /r    Move if not parity (PF=0).
+const CMOVNP_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4b, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnp",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVPO reg16, reg/mem16    0F 4B /r    Move if parity odd (PF=0).
+const CMOVPO_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4b, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovpo",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVPO reg32, reg/mem32    0F 4B /r    Move if parity odd (PF=0).
+const CMOVPO_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4b, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovpo",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVPO reg64, reg/mem64    0F 4B /r    Move if parity odd (PF=0).
+const CMOVPO_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4b, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovpo",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVL reg16, reg/mem16    0F 4C /r    Move if less (SF<>OF).
+const CMOVL_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4c, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovl",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVL reg32, reg/mem32    0F 4C /r    Move if less (SF<>OF).
+const CMOVL_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4c, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovl",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVL reg64, reg/mem64    0F 4C /r    Move if less (SF<>OF).
+const CMOVL_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4c, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovl",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNGE reg16, reg/mem16    0F 4C /r    Move if not greater or equal (SF<>OF).
+const CMOVNGE_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4c, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnge",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNGE reg32, reg/mem32    0F 4C /r    Move if not greater or equal (SF<>OF).
+const CMOVNGE_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4c, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnge",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNGE reg64, reg/mem64    0F 4C /r    Move if not greater or equal (SF<>OF).
+const CMOVNGE_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4c, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnge",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVGE reg16, reg/mem16    0F 4D /r    Move if greater or equal (SF=OF).
+const CMOVGE_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4d, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovge",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVGE reg32, reg/mem32    0F 4D /r    Move if greater or equal (SF=OF).
+const CMOVGE_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4d, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovge",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVGE reg64, reg/mem64    0F 4D /r    Move if greater or equal (SF=OF).
+const CMOVGE_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4d, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovge",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNL reg16, reg/mem16    0F 4D /r    Move if not less (SF=OF).
+const CMOVNL_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4d, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnl",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNL reg32, reg/mem32    0F 4D /r    Move if not less (SF=OF).
+const CMOVNL_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4d, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnl",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNL reg64, reg/mem64    0F 4D /r    Move if not less (SF=OF).
+const CMOVNL_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4d, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnl",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVLE reg16, reg/mem16    0F 4E /r    Move if less or equal (ZF=1 or SF<>OF).
+const CMOVLE_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4e, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovle",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVLE reg32, reg/mem32    0F 4E /r    Move if less or equal (ZF=1 or SF<>OF).
+const CMOVLE_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4e, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovle",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVLE reg64, reg/mem64    0F 4E /r    Move if less or equal (ZF=1 or SF<>OF).
+const CMOVLE_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4e, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovle",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNG reg16, reg/mem16    0F 4E /r    Move if not greater (ZF=1 or SF<>OF).
+const CMOVNG_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4e, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovng",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNG reg32, reg/mem32    0F 4E /r    Move if not greater (ZF=1 or SF<>OF).
+const CMOVNG_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4e, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovng",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNG reg64, reg/mem64    0F 4E /r    Move if not greater (ZF=1 or SF<>OF).
+const CMOVNG_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4e, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovng",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVG reg16, reg/mem16    0F 4F /r    Move if greater (ZF=0 and SF=OF).
+const CMOVG_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4f, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovg",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVG reg32, reg/mem32    0F 4F /r    Move if greater (ZF=0 and SF=OF).
+const CMOVG_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4f, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovg",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVG reg64, reg/mem64    0F 4F /r    Move if greater (ZF=0 and SF=OF).
+const CMOVG_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4f, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovg",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMOVNLE reg16, reg/mem16    0F 4F /r    Move if not less or equal (ZF=0 and SF=OF).
+const CMOVNLE_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4f, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnle",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMOVNLE reg32, reg/mem32    0F 4F /r    Move if not less or equal (ZF=0 and SF=OF).
+const CMOVNLE_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4f, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnle",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMOVNLE reg64, reg/mem64    0F 4F /r    Move if not less or equal (ZF=0 and SF=OF).
+const CMOVNLE_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x4f, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmovnle",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CMP AL, imm83C ib
+const CMP_AL_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3c, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
+    },
+};
+
+// CMP AX, imm163D iw
+const CMP_AX_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
+    },
+};
+
+// CMP EAX, imm323D id
+const CMP_EAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
+    },
+};
+
+// CMP RAX, imm323D id
+const CMP_RAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
+    },
+};
+
+// CMP reg/mem8, imm880 /7 ib
+const CMP_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x80, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// CMP reg/mem16, imm1681 /7 iw
+const CMP_RM16_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
+    },
+};
+
+// CMP reg/mem32, imm3281 /7 id
+const CMP_RM32_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
+    },
+};
+
+// CMP reg/mem64, imm3281 /7 id
+const CMP_RM64_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
+    },
+};
+
+// CMP reg/mem16, imm883 /7 ib
+const CMP_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
+    },
+};
+
+// CMP reg/mem32, imm883 /7 ib
+const CMP_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
+    },
+};
+
+// CMP reg/mem64, imm883 /7 ib
+const CMP_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
+    },
+};
+
+// CMP reg/mem8, reg838 /r
+const CMP_RM8_R8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x38, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
+    },
+};
+
+// CMP reg/mem16, reg1639 /r
+const CMP_RM16_R16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x39, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
+    },
+};
+
+// CMP reg/mem32, reg3239 /r
+const CMP_RM32_R32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x39, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
+    },
+};
+
+// CMP reg/mem64, reg6439 /r
+const CMP_RM64_R64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x39, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
+    },
+};
+
+// CMP reg8, reg/mem83A /r
+const CMP_R8_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3a, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
+    },
+};
+
+// CMP reg16, reg/mem163B /r
+const CMP_R16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// CMP reg32, reg/mem323B /r
+const CMP_R32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// CMP reg64, reg/mem643B /r
+const CMP_R64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x3b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cmp",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// CPUID    0F A2
+const CPUID: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xa2, 0x00], 2),
+        modrm: None,
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cpuid",
+        operands: [None, None, None],
+    },
+};
+
+// CQO REX.W + 99
+const CQO: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x48, 0x99, 0x00], 2),
+        modrm: None,
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "cqo",
+        operands: [None, None, None],
+    },
+};
+
+// DEC reg/mem8FE /1
+const DEC_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xfe, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "dec",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// DEC reg/mem16FF /1
+const DEC_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "dec",
+        operands: [Some(OperandType::Rm16), None, None],
+    },
+};
+
+// DEC reg/mem32FF /1
+const DEC_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "dec",
+        operands: [Some(OperandType::Rm32), None, None],
+    },
+};
+
+// DEC reg/mem64FF /1
+const DEC_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "dec",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// DEC reg1648 +rw
+const DEC_R16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x48, 0x00, 0x00], 1),
+        modrm: None,
+        imm: None,
+        opecode_register: Some(OpecodeRegisterRule::Rw),
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "dec",
+        operands: [Some(OperandType::R16), None, None],
+    },
+};
+
+// DEC reg3248 +rd
+const DEC_R32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x48, 0x00, 0x00], 1),
+        modrm: None,
+        imm: None,
+        opecode_register: Some(OpecodeRegisterRule::Rd),
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "dec",
+        operands: [Some(OperandType::R32), None, None],
+    },
+};
+
+// DIV reg/mem8F6 /6
+const DIV_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf6, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "div",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// DIV reg/mem16F7 /6
+const DIV_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "div",
+        operands: [Some(OperandType::Rm16), None, None],
+    },
+};
+
+// DIV reg/mem32F7 /6
+const DIV_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "div",
+        operands: [Some(OperandType::Rm32), None, None],
+    },
+};
+
+// DIV reg/mem64F7 /6
+const DIV_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "div",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// IDIV reg/mem8F6 /7
+const IDIV_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf6, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "idiv",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// IDIV reg/mem16F7 /7
+const IDIV_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "idiv",
+        operands: [Some(OperandType::Rm16), None, None],
+    },
+};
+
+// IDIV reg/mem32F7 /7
+const IDIV_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "idiv",
+        operands: [Some(OperandType::Rm32), None, None],
+    },
+};
+
+// IDIV reg/mem64F7 /7
+const IDIV_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "idiv",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// IMUL reg/mem8F6 /5
+// IMUL reg/mem16F7 /5
+// IMUL reg/mem32F7 /5
+// IMUL reg/mem64F7 /5
 // IMUL reg16, reg/mem160F AF /r
 // IMUL reg32, reg/mem320F AF /r
 // IMUL reg64, reg/mem640F AF /r
@@ -1657,150 +3403,4803 @@ IN AX, DXEDInput a word from the port at the address specified by
 the DX register and put it into the AX register.
 IN EAX, DXED
 
-IN AL, imm8E4 ibInput a byte from the port at the address specified by
-imm8 and put it into the AL register.
-IN AX, imm8E5 ibInput a word from the port at the address specified by
-imm8 and put it into the AX register.
-IN EAX, imm8E5 ibInput a doubleword from the port at the address
-specified by imm8 and put it into the EAX register.
-IN AL, DXECInput a byte from the port at the address specified by the
-DX register and put it into the AL register.
-IN AX, DXEDInput a word from the port at the address specified by
-the DX register and put it into the AX register.
-IN EAX, DXED
-*/
+IN AL, imm8E4 ibInput a byte from the port at the address specified by
+imm8 and put it into the AL register.
+IN AX, imm8E5 ibInput a word from the port at the address specified by
+imm8 and put it into the AX register.
+IN EAX, imm8E5 ibInput a doubleword from the port at the address
+specified by imm8 and put it into the EAX register.
+IN AL, DXECInput a byte from the port at the address specified by the
+DX register and put it into the AL register.
+IN AX, DXEDInput a word from the port at the address specified by
+the DX register and put it into the AX register.
+IN EAX, DXED
+*/
+
+// INC reg/mem8     FE /0
+const INC_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xfe, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "inc",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// INC reg/mem16    FF /0
+const INC_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "inc",
+        operands: [Some(OperandType::Rm16), None, None],
+    },
+};
+
+// INC reg/mem32    FF /0
+const INC_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "inc",
+        operands: [Some(OperandType::Rm32), None, None],
+    },
+};
+
+// INC reg/mem64    FF /0
+const INC_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "inc",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// JO rel32off    0F 80 cd    Jump near if overflow (OF=1).
+const JO_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x80, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jo",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JO rel8off    70 cb    Jump short if overflow (OF=1).
+const JO_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x70, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jo",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNO rel32off    0F 81 cd    Jump near if not overflow (OF=0).
+const JNO_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x81, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jno",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNO rel8off    71 cb    Jump short if not overflow (OF=0).
+const JNO_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x71, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jno",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JB rel32off    0F 82 cd    Jump near if below (CF=1).
+const JB_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x82, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jb",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JB rel8off    72 cb    Jump short if below (CF=1).
+const JB_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x72, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jb",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JC rel32off    0F 82 cd    Jump near if carry (CF=1).
+const JC_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x82, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jc",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JC rel8off    72 cb    Jump short if carry (CF=1).
+const JC_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x72, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jc",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNAE rel32off    0F 82 cd    Jump near if not above or equal (CF=1).
+const JNAE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x82, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnae",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNAE rel8off    72 cb    Jump short if not above or equal (CF=1).
+const JNAE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x72, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnae",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JAE rel32off    0F 83 cd    Jump near if above or equal (CF=0).
+const JAE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x83, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jae",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JAE rel8off    73 cb    Jump short if above or equal (CF=0).
+const JAE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x73, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jae",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNB rel32off    0F 83 cd    Jump near if not below (CF=0).
+const JNB_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x83, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnb",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNB rel8off    73 cb    Jump short if not below (CF=0).
+const JNB_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x73, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnb",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNC rel32off    0F 83 cd    Jump near if not carry (CF=0).
+const JNC_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x83, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnc",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNC rel8off    73 cb    Jump short if not carry (CF=0).
+const JNC_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x73, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnc",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JE rel32off    0F 84 cd    Jump near if equal (ZF=1).
+const JE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x84, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "je",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JE rel8off    74 cb    Jump short if equal (ZF=1).
+const JE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x74, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "je",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JZ rel32off    0F 84 cd    Jump near if zero (ZF=1).
+const JZ_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x84, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jz",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JZ rel8off    74 cb    Jump short if zero (ZF=1).
+const JZ_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x74, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jz",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNE rel32off    0F 85 cd    Jump near if not equal (ZF=0).
+const JNE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x85, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jne",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNE rel8off    75 cb    Jump short if not equal (ZF=0).
+const JNE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x75, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jne",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNZ rel32off    0F 85 cd    Jump near if not zero (ZF=0).
+const JNZ_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x85, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnz",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNZ rel8off    75 cb    Jump short if not zero (ZF=0).
+const JNZ_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x75, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnz",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JBE rel32off    0F 86 cd    Jump near if below or equal (CF=1 or ZF=1).
+const JBE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x86, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jbe",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JBE rel8off    76 cb    Jump short if below or equal (CF=1 or ZF=1).
+const JBE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x76, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jbe",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNA rel32off    0F 86 cd    Jump near if not above (CF=1 or ZF=1).
+const JNA_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x86, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jna",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNA rel8off    76 cb    Jump short if not above (CF=1 or ZF=1).
+const JNA_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x76, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jna",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JA rel32off    0F 87 cd    Jump near if above (CF=0 and ZF=0).
+const JA_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x87, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "ja",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JA rel8off    77 cb    Jump short if above (CF=0 and ZF=0).
+const JA_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x77, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "ja",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNBE rel32off    0F 87 cd    Jump near if not below or equal (CF=0 and ZF=0).
+const JNBE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x87, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnbe",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNBE rel8off    77 cb    Jump short if not below or equal (CF=0 and ZF=0).
+const JNBE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x77, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnbe",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JS rel32off    0F 88 cd    Jump near if sign (SF=1).
+const JS_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x88, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "js",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JS rel8off    78 cb    Jump short if sign (SF=1).
+const JS_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x78, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "js",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNS rel32off    0F 89 cd    Jump near if not sign (SF=0).
+const JNS_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x89, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jns",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNS rel8off    79 cb    Jump short if not sign (SF=0).
+const JNS_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x79, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jns",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JP rel32off    0F 8A cd    Jump near if parity (PF=1).
+const JP_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8a, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jp",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JP rel8off    7A cb    Jump short if parity (PF=1).
+const JP_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7a, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jp",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JPE rel32off    0F 8A cd    Jump near if parity even (PF=1).
+const JPE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8a, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jpe",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JPE rel8off    7A cb    Jump short if parity even (PF=1).
+const JPE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7a, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jpe",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNP rel32off    0F 8B cd    Jump near if not parity (PF=0).
+const JNP_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8b, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnp",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNP rel8off    7B cb    Jump short if not parity (PF=0).
+const JNP_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7b, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnp",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JPO rel32off    0F 8B cd    Jump near if parity odd (PF=0).
+const JPO_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8b, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jpo",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JPO rel8off    7B cb    Jump short if parity odd (PF=0).
+const JPO_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7b, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jpo",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JL rel32off    0F 8C cd    Jump near if less (SF<>OF).
+const JL_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8c, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jl",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JL rel8off    7C cb    Jump short if less (SF<>OF).
+const JL_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7c, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jl",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNGE rel32off    0F 8C cd    Jump near if not greater or equal (SF<>OF).
+const JNGE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8c, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnge",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNGE rel8off    7C cb    Jump short if not greater or equal (SF<>OF).
+const JNGE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7c, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnge",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JGE rel32off    0F 8D cd    Jump near if greater or equal (SF=OF).
+const JGE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8d, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jge",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JGE rel8off    7D cb    Jump short if greater or equal (SF=OF).
+const JGE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jge",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNL rel32off    0F 8D cd    Jump near if not less (SF=OF).
+const JNL_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8d, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnl",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNL rel8off    7D cb    Jump short if not less (SF=OF).
+const JNL_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnl",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JLE rel32off    0F 8E cd    Jump near if less or equal (ZF=1 or SF<>OF).
+const JLE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8e, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jle",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JLE rel8off    7E cb    Jump short if less or equal (ZF=1 or SF<>OF).
+const JLE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7e, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jle",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNG rel32off    0F 8E cd    Jump near if not greater (ZF=1 or SF<>OF).
+const JNG_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8e, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jng",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNG rel8off    7E cb    Jump short if not greater (ZF=1 or SF<>OF).
+const JNG_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7e, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jng",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JG rel32off    0F 8F cd    Jump near if greater (ZF=0 and SF=OF).
+const JG_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8f, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jg",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JG rel8off    7F cb    Jump short if greater (ZF=0 and SF=OF).
+const JG_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7f, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jg",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// JNLE rel32off    0F 8F cd    Jump near if not less or equal (ZF=0 and SF=OF).
+const JNLE_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x8f, 0x00], 2),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnle",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// JNLE rel8off    7F cb    Jump short if not less or equal (ZF=0 and SF=OF).
+const JNLE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x7f, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jnle",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// NEAR JMP rel32off    E9 cd
+const NEAR_JMP_REL32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xe9, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jmp",
+        operands: [Some(OperandType::Rel32), None, None],
+    },
+};
+
+// SHORT JMP rel8off    EB cb
+const SHORT_JMP_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xeb, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jmp",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// NEAR JMP reg/mem64    FF /4
+const NEAR_JMP_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jmp",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// JRCXZ rel8off    E3 cb    Jump short if RCX is 0.
+const JRCXZ_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xe3, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "jrcxz",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// LOOP rel8off    E2 cb    Decrement RCX, then jump short if RCX is not 0.
+const LOOP_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xe2, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "loop",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// LOOPE rel8off    E1 cb    Decrement RCX, then jump short if RCX is not 0 and ZF=1.
+const LOOPE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xe1, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "loope",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// LOOPZ rel8off    E1 cb    Decrement RCX, then jump short if RCX is not 0 and ZF=1.
+const LOOPZ_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xe1, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "loopz",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// LOOPNE rel8off    E0 cb    Decrement RCX, then jump short if RCX is not 0 and ZF=0.
+const LOOPNE_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xe0, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "loopne",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// LOOPNZ rel8off    E0 cb    Decrement RCX, then jump short if RCX is not 0 and ZF=0.
+const LOOPNZ_REL8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xe0, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "loopnz",
+        operands: [Some(OperandType::Rel8), None, None],
+    },
+};
+
+// NEG reg/mem8     F6 /3
+const NEG_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf6, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "neg",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// NEG reg/mem16    F7 /3
+const NEG_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "neg",
+        operands: [Some(OperandType::Rm16), None, None],
+    },
+};
+
+// NEG reg/mem32    F7 /3
+const NEG_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "neg",
+        operands: [Some(OperandType::Rm32), None, None],
+    },
+};
+
+// NEG reg/mem64    F7 /3
+const NEG_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "neg",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// NOT reg/mem8     F6 /2
+const NOT_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf6, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "not",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// NOT reg/mem16    F7 /2
+const NOT_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "not",
+        operands: [Some(OperandType::Rm16), None, None],
+    },
+};
+
+// NOT reg/mem32    F7 /2
+const NOT_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "not",
+        operands: [Some(OperandType::Rm32), None, None],
+    },
+};
+
+// NOT reg/mem64    F7 /2
+const NOT_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "not",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// OR AL, imm8     0C ib
+const OR_AL_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0c, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
+    },
+};
+
+// OR AX, imm16    0D iw
+const OR_AX_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
+    },
+};
+
+// OR EAX, imm32   0D id
+const OR_EAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
+    },
+};
+
+// OR RAX, imm32   0D id
+const OR_RAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
+    },
+};
+
+// OR reg/mem8, imm8   80 /1 ib
+const OR_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x80, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// OR reg/mem16, imm16     81 /1 iw
+const OR_RM16_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
+    },
+};
+
+// OR reg/mem32, imm32     81 /1 id
+const OR_RM32_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
+    },
+};
+
+// OR reg/mem64, imm32     81 /1 id
+const OR_RM64_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
+    },
+};
+
+// OR reg/mem16, imm8      83 /1 ib
+const OR_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
+    },
+};
+
+// OR reg/mem32, imm8      83 /1 ib
+const OR_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
+    },
+};
+
+// OR reg/mem64, imm8      83 /1 ib
+const OR_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
+    },
+};
+
+// OR reg/mem8, reg8       08 /r
+const OR_RM8_REG8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x08, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
+    },
+};
+
+// OR reg/mem16, reg16     09 /r
+const OR_RM16_REG16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x09, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
+    },
+};
+
+// OR reg/mem32, reg32     09 /r
+const OR_RM32_REG32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x09, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
+    },
+};
+
+// OR reg/mem64, reg64     09 /r
+const OR_RM64_REG64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x09, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
+    },
+};
+
+// OR reg8, reg/mem8       0A /r
+const OR_REG8_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0a, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
+    },
+};
+
+// OR reg16, reg/mem16     0B /r
+const OR_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// OR reg32, reg/mem32     0B /r
+const OR_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// OR reg64, reg/mem64     0B /r
+const OR_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "or",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// RCL reg/mem8, 1    D0 /2
+const RCL_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// RCL reg/mem8, CL    D2 /2
+const RCL_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// RCL reg/mem8, imm8    C0 /2 ib
+const RCL_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// RCL reg/mem16, 1    D1 /2
+const RCL_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// RCL reg/mem16, CL    D3 /2
+const RCL_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// RCL reg/mem16, imm8    C1 /2 ib
+const RCL_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// RCL reg/mem32, 1    D1 /2
+const RCL_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// RCL reg/mem32, CL    D3 /2
+const RCL_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// RCL reg/mem32, imm8    C1 /2 ib
+const RCL_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// RCL reg/mem64, 1    D1 /2
+const RCL_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// RCL reg/mem64, CL    D3 /2
+const RCL_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// RCL reg/mem64, imm8    C1 /2 ib
+const RCL_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(2)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcl",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// RCR reg/mem8, 1    D0 /3
+const RCR_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// RCR reg/mem8, CL    D2 /3
+const RCR_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// RCR reg/mem8, imm8    C0 /3 ib
+const RCR_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// RCR reg/mem16, 1    D1 /3
+const RCR_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// RCR reg/mem16, CL    D3 /3
+const RCR_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// RCR reg/mem16, imm8    C1 /3 ib
+const RCR_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// RCR reg/mem32, 1    D1 /3
+const RCR_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// RCR reg/mem32, CL    D3 /3
+const RCR_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// RCR reg/mem32, imm8    C1 /3 ib
+const RCR_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// RCR reg/mem64, 1    D1 /3
+const RCR_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// RCR reg/mem64, CL    D3 /3
+const RCR_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// RCR reg/mem64, imm8    C1 /3 ib
+const RCR_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rcr",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROL reg/mem8, 1    D0 /0
+const ROL_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// ROL reg/mem8, CL    D2 /0
+const ROL_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// ROL reg/mem8, imm8    C0 /0 ib
+const ROL_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROL reg/mem16, 1    D1 /0
+const ROL_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// ROL reg/mem16, CL    D3 /0
+const ROL_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// ROL reg/mem16, imm8    C1 /0 ib
+const ROL_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROL reg/mem32, 1    D1 /0
+const ROL_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// ROL reg/mem32, CL    D3 /0
+const ROL_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// ROL reg/mem32, imm8    C1 /0 ib
+const ROL_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROL reg/mem64, 1    D1 /0
+const ROL_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// ROL reg/mem64, CL    D3 /0
+const ROL_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// ROL reg/mem64, imm8    C1 /0 ib
+const ROL_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "rol",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROR reg/mem8, 1    D0 /1
+const ROR_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// ROR reg/mem8, CL    D2 /1
+const ROR_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// ROR reg/mem8, imm8    C0 /1 ib
+const ROR_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROR reg/mem16, 1    D1 /1
+const ROR_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// ROR reg/mem16, CL    D3 /1
+const ROR_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// ROR reg/mem16, imm8    C1 /1 ib
+const ROR_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROR reg/mem32, 1    D1 /1
+const ROR_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// ROR reg/mem32, CL    D3 /1
+const ROR_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// ROR reg/mem32, imm8    C1 /1 ib
+const ROR_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// ROR reg/mem64, 1    D1 /1
+const ROR_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// ROR reg/mem64, CL    D3 /1
+const ROR_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// ROR reg/mem64, imm8    C1 /1 ib
+const ROR_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(1)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "ror",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// PUSH reg64   50 +rq
+const PUSH_R64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x50, 0x00, 0x00], 1),
+        modrm: None,
+        imm: None,
+        opecode_register: Some(OpecodeRegisterRule::Rq),
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "push",
+        operands: [Some(OperandType::R64), None, None],
+    },
+};
+
+// PUSH reg/mem64   FF /6
+const PUSH_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "push",
+        operands: [Some(OperandType::Rm64), None, None],
+    },
+};
+
+// PUSH imm64   68 id
+const PUSH_IMM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x68, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "push",
+        operands: [Some(OperandType::SImm32), None, None],
+    },
+};
+
+// MOV reg/mem64, reg64     89 /r
+const MOV_RM64_R64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x89, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "mov",
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
+    },
+};
+
+// MOV reg64, imm64     B8 +rq iq
+const MOV_R64_IMM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xb8, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Iq),
+        opecode_register: Some(OpecodeRegisterRule::Rq),
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "mov",
+        operands: [Some(OperandType::R64), Some(OperandType::Imm64), None],
+    },
+};
+
+// MOV reg/mem64, imm32     C7 /0 id
+const MOV_RM64_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "mov",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
+    },
+};
+
+// MOV reg32, imm32     B8 +rd id
+const MOV_R32_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xb8, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: Some(OpecodeRegisterRule::Rd),
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "mov",
+        operands: [Some(OperandType::R32), Some(OperandType::Imm32), None],
+    },
+};
+
+// POP reg64    58 +rq
+const POP_R64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x58, 0x00, 0x00], 1),
+        modrm: None,
+        imm: None,
+        opecode_register: Some(OpecodeRegisterRule::Rq),
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "pop",
+        operands: [Some(OperandType::R64), None, None],
+    },
+};
+
+// C3 RET
+const NEAR_RET: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc3, 0x00, 0x00], 1),
+        modrm: None,
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Oq,
+    },
+    expression: Expression {
+        mnemonic: "ret",
+        operands: [None, None, None],
+    },
+};
+
+// SETO reg/mem8    0F 90 /0    Set byte if overflow (OF=1).
+const SETO_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x90, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "seto",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNO reg/mem8    0F 91 /0    Set byte if not overflow (OF=0).
+const SETNO_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x91, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setno",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETB reg/mem8    0F 92 /0    Set byte if below (CF=1).
+const SETB_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x92, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setb",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETC reg/mem8    0F 92 /0    Set byte if carry (CF=1).
+const SETC_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x92, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setc",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNAE reg/mem8    0F 92 /0    Set byte if not above or equal (CF=1).
+const SETNAE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x92, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnae",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETAE reg/mem8    0F 93 /0    Set byte if above or equal (CF=0).
+const SETAE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x93, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setae",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNB reg/mem8    0F 93 /0    Set byte if not below (CF=0).
+const SETNB_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x93, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnb",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNC reg/mem8    0F 93 /0    Set byte if not carry (CF=0).
+const SETNC_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x93, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnc",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETE reg/mem8    0F 94 /0    Set byte if equal (ZF=1).
+const SETE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x94, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sete",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETZ reg/mem8    0F 94 /0    Set byte if zero (ZF=1).
+const SETZ_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x94, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setz",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNE reg/mem8    0F 95 /0    Set byte if not equal (ZF=0).
+const SETNE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x95, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setne",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNZ reg/mem8    0F 95 /0    Set byte if not zero (ZF=0).
+const SETNZ_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x95, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnz",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETBE reg/mem8    0F 96 /0    Set byte if below or equal (CF=1 or ZF=1).
+const SETBE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x96, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setbe",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNA reg/mem8    0F 96 /0    Set byte if not above (CF=1 or ZF=1).
+const SETNA_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x96, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setna",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETA reg/mem8    0F 97 /0    Set byte if above (CF=0 and ZF=0).
+const SETA_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x97, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "seta",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNBE reg/mem8    0F 97 /0    Set byte if not below or equal (CF=0 and ZF=0).
+const SETNBE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x97, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnbe",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETS reg/mem8    0F 98 /0    Set byte if sign (SF=1).
+const SETS_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x98, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sets",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNS reg/mem8    0F 99 /0    Set byte if not sign (SF=0).
+const SETNS_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x99, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setns",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETP reg/mem8    0F 9A /0    Set byte if parity (PF=1).
+const SETP_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9a, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setp",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETPE reg/mem8    0F 9A /0    Set byte if parity even (PF=1).
+const SETPE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9a, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setpe",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNP reg/mem8    0F 9B /0    Set byte if not parity (PF=0).
+const SETNP_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9b, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnp",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETPO reg/mem8    0F 9B /0    Set byte if parity odd (PF=0).
+const SETPO_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9b, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setpo",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETL reg/mem8    0F 9C /0    Set byte if less (SF<>OF).
+const SETL_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9c, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setl",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNGE reg/mem8    0F 9C /0    Set byte if not greater or equal (SF<>OF).
+const SETNGE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9c, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnge",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETGE reg/mem8    0F 9D /0    Set byte if greater or equal (SF=OF).
+const SETGE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9d, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setge",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNL reg/mem8    0F 9D /0    Set byte if not less (SF=OF).
+const SETNL_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9d, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnl",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETLE reg/mem8    0F 9E /0    Set byte if less or equal (ZF=1 or SF<>OF).
+const SETLE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9e, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setle",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNG reg/mem8    0F 9E /0    Set byte if not greater (ZF=1 or SF<>OF).
+const SETNG_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9e, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setng",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETG reg/mem8    0F 9F /0    Set byte if greater (ZF=0 and SF=OF).
+const SETG_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9f, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setg",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SETNLE reg/mem8    0F 9F /0    Set byte if not less or equal (ZF=0 and SF=OF).
+const SETNLE_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0x9f, 0x00], 2),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "setnle",
+        operands: [Some(OperandType::Rm8), None, None],
+    },
+};
+
+// SBB AL, imm8     1C ib
+const SBB_AL_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1c, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
+    },
+};
+
+// SBB AX, imm16    1D iw
+const SBB_AX_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
+    },
+};
+
+// SBB EAX, imm32   1D id
+const SBB_EAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
+    },
+};
+
+// SBB RAX, imm32   1D id
+const SBB_RAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
+    },
+};
+
+// SBB reg/mem8, imm8   80 /3 ib
+const SBB_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x80, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// SBB reg/mem16, imm16     81 /3 iw
+const SBB_RM16_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
+    },
+};
+
+// SBB reg/mem32, imm32     81 /3 id
+const SBB_RM32_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
+    },
+};
+
+// SBB reg/mem64, imm32     81 /3 id
+const SBB_RM64_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
+    },
+};
+
+// SBB reg/mem16, imm8      83 /3 ib
+const SBB_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
+    },
+};
+
+// SBB reg/mem32, imm8      83 /3 ib
+const SBB_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
+    },
+};
+
+// SBB reg/mem64, imm8      83 /3 ib
+const SBB_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(3)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
+    },
+};
+
+// SBB reg/mem8, reg8       18 /r
+const SBB_RM8_REG8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x18, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
+    },
+};
+
+// SBB reg/mem16, reg16     19 /r
+const SBB_RM16_REG16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x19, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
+    },
+};
+
+// SBB reg/mem32, reg32     19 /r
+const SBB_RM32_REG32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x19, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
+    },
+};
+
+// SBB reg/mem64, reg64     19 /r
+const SBB_RM64_REG64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x19, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
+    },
+};
+
+// SBB reg8, reg/mem8       1A /r
+const SBB_REG8_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1a, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
+    },
+};
+
+// SBB reg16, reg/mem16     1B /r
+const SBB_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// SBB reg32, reg/mem32     1B /r
+const SBB_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// SBB reg64, reg/mem64     1B /r
+const SBB_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x1b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sbb",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// SAL reg/mem8, 1    D0 /4
+const SAL_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// SAL reg/mem8, CL    D2 /4
+const SAL_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// SAL reg/mem8, imm8    C0 /4 ib
+const SAL_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// SAL reg/mem16, 1    D1 /4
+const SAL_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// SAL reg/mem16, CL    D3 /4
+const SAL_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// SAL reg/mem16, imm8    C1 /4 ib
+const SAL_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// SAL reg/mem32, 1    D1 /4
+const SAL_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// SAL reg/mem32, CL    D3 /4
+const SAL_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// SAL reg/mem32, imm8    C1 /4 ib
+const SAL_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// SAL reg/mem64, 1    D1 /4
+const SAL_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// SAL reg/mem64, CL    D3 /4
+const SAL_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// SAL reg/mem64, imm8    C1 /4 ib
+const SAL_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sal",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// SAR reg/mem8, 1    D0 /7
+const SAR_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// SAR reg/mem8, CL    D2 /7
+const SAR_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// SAR reg/mem8, imm8    C0 /7 ib
+const SAR_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// SAR reg/mem16, 1    D1 /7
+const SAR_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// SAR reg/mem16, CL    D3 /7
+const SAR_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// SAR reg/mem16, imm8    C1 /7 ib
+const SAR_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// SAR reg/mem32, 1    D1 /7
+const SAR_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// SAR reg/mem32, CL    D3 /7
+const SAR_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// SAR reg/mem32, imm8    C1 /7 ib
+const SAR_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// SAR reg/mem64, 1    D1 /7
+const SAR_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// SAR reg/mem64, CL    D3 /7
+const SAR_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// SAR reg/mem64, imm8    C1 /7 ib
+const SAR_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(7)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sar",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHL reg/mem8, 1    D0 /4
+const SHL_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// SHL reg/mem8, CL    D2 /4
+const SHL_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// SHL reg/mem8, imm8    C0 /4 ib
+const SHL_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHL reg/mem16, 1    D1 /4
+const SHL_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// SHL reg/mem16, CL    D3 /4
+const SHL_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// SHL reg/mem16, imm8    C1 /4 ib
+const SHL_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHL reg/mem32, 1    D1 /4
+const SHL_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// SHL reg/mem32, CL    D3 /4
+const SHL_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// SHL reg/mem32, imm8    C1 /4 ib
+const SHL_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHL reg/mem64, 1    D1 /4
+const SHL_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// SHL reg/mem64, CL    D3 /4
+const SHL_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// SHL reg/mem64, imm8    C1 /4 ib
+const SHL_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(4)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shl",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHLD reg/mem16, reg16, imm8    0F A4 /r ib
+const SHLD_RM16_REG16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xa4, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shld",
+        operands: [
+            Some(OperandType::Rm16),
+            Some(OperandType::R16),
+            Some(OperandType::Imm8),
+        ],
+    },
+};
+
+// SHLD reg/mem16, reg16, CL    0F A5 /r
+const SHLD_RM16_REG16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xa5, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shld",
+        operands: [
+            Some(OperandType::Rm16),
+            Some(OperandType::R16),
+            Some(OperandType::Cl),
+        ],
+    },
+};
+
+// SHLD reg/mem32, reg32, imm8    0F A4 /r ib
+const SHLD_RM32_REG32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xa4, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shld",
+        operands: [
+            Some(OperandType::Rm32),
+            Some(OperandType::R32),
+            Some(OperandType::Imm8),
+        ],
+    },
+};
+
+// SHLD reg/mem32, reg32, CL    0F A5 /r
+const SHLD_RM32_REG32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xa5, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shld",
+        operands: [
+            Some(OperandType::Rm32),
+            Some(OperandType::R32),
+            Some(OperandType::Cl),
+        ],
+    },
+};
+
+// SHLD reg/mem64, reg64, imm8    0F A4 /r ib
+const SHLD_RM64_REG64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xa4, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shld",
+        operands: [
+            Some(OperandType::Rm64),
+            Some(OperandType::R64),
+            Some(OperandType::Imm8),
+        ],
+    },
+};
+
+// SHLD reg/mem64, reg64, CL    0F A5 /r
+const SHLD_RM64_REG64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xa5, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shld",
+        operands: [
+            Some(OperandType::Rm64),
+            Some(OperandType::R64),
+            Some(OperandType::Cl),
+        ],
+    },
+};
+
+// SHR reg/mem8, 1    D0 /5
+const SHR_RM8_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm8), Some(OperandType::One), None],
+    },
+};
+
+// SHR reg/mem8, CL    D2 /5
+const SHR_RM8_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd2, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Cl), None],
+    },
+};
+
+// SHR reg/mem8, imm8    C0 /5 ib
+const SHR_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc0, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHR reg/mem16, 1    D1 /5
+const SHR_RM16_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm16), Some(OperandType::One), None],
+    },
+};
+
+// SHR reg/mem16, CL    D3 /5
+const SHR_RM16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Cl), None],
+    },
+};
+
+// SHR reg/mem16, imm8    C1 /5 ib
+const SHR_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHR reg/mem32, 1    D1 /5
+const SHR_RM32_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm32), Some(OperandType::One), None],
+    },
+};
+
+// SHR reg/mem32, CL    D3 /5
+const SHR_RM32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Cl), None],
+    },
+};
+
+// SHR reg/mem32, imm8    C1 /5 ib
+const SHR_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHR reg/mem64, 1    D1 /5
+const SHR_RM64_ONE: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm64), Some(OperandType::One), None],
+    },
+};
+
+// SHR reg/mem64, CL    D3 /5
+const SHR_RM64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xd3, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Cl), None],
+    },
+};
+
+// SHR reg/mem64, imm8    C1 /5 ib
+const SHR_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xc1, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shr",
+        operands: [Some(OperandType::Rm64), Some(OperandType::Imm8), None],
+    },
+};
+
+// SHRD reg/mem16, reg16, imm8    0F AC /r ib
+const SHRD_RM16_REG16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xac, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shrd",
+        operands: [
+            Some(OperandType::Rm16),
+            Some(OperandType::R16),
+            Some(OperandType::Imm8),
+        ],
+    },
+};
+
+// SHRD reg/mem16, reg16, CL    0F AD /r
+const SHRD_RM16_REG16_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xad, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shrd",
+        operands: [
+            Some(OperandType::Rm16),
+            Some(OperandType::R16),
+            Some(OperandType::Cl),
+        ],
+    },
+};
+
+// SHRD reg/mem32, reg32, imm8    0F AC /r ib
+const SHRD_RM32_REG32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xac, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shrd",
+        operands: [
+            Some(OperandType::Rm32),
+            Some(OperandType::R32),
+            Some(OperandType::Imm8),
+        ],
+    },
+};
+
+// SHRD reg/mem32, reg32, CL    0F AD /r
+const SHRD_RM32_REG32_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xad, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shrd",
+        operands: [
+            Some(OperandType::Rm32),
+            Some(OperandType::R32),
+            Some(OperandType::Cl),
+        ],
+    },
+};
+
+// SHRD reg/mem64, reg64, imm8    0F AC /r ib
+const SHRD_RM64_REG64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xac, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shrd",
+        operands: [
+            Some(OperandType::Rm64),
+            Some(OperandType::R64),
+            Some(OperandType::Imm8),
+        ],
+    },
+};
+
+// SHRD reg/mem64, reg64, CL    0F AD /r
+const SHRD_RM64_REG64_CL: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x0f, 0xad, 0x00], 2),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "shrd",
+        operands: [
+            Some(OperandType::Rm64),
+            Some(OperandType::R64),
+            Some(OperandType::Cl),
+        ],
+    },
+};
+
+// SUB AL, imm8     2C ib
+const SUB_AL_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2c, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
+    },
+};
+
+// SUB AX, imm16    2D iw
+const SUB_AX_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
+    },
+};
+
+// SUB EAX, imm32   2D id
+const SUB_EAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
+    },
+};
+
+// SUB RAX, imm32   2D id
+const SUB_RAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2d, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
+    },
+};
+
+// SUB reg/mem8, imm8   80 /5 ib
+const SUB_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x80, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// SUB reg/mem16, imm16     81 /5 iw
+const SUB_RM16_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
+    },
+};
+
+// SUB reg/mem32, imm32     81 /5 id
+const SUB_RM32_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
+    },
+};
+
+// SUB reg/mem64, imm32     81 /5 id
+const SUB_RM64_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
+    },
+};
+
+// SUB reg/mem16, imm8      83 /5 ib
+const SUB_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
+    },
+};
+
+// SUB reg/mem32, imm8      83 /5 ib
+const SUB_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
+    },
+};
+
+// SUB reg/mem64, imm8      83 /5 ib
+const SUB_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(5)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
+    },
+};
+
+// SUB reg/mem8, reg8       28 /r
+const SUB_RM8_REG8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x28, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
+    },
+};
+
+// SUB reg/mem16, reg16     29 /r
+const SUB_RM16_REG16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x29, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
+    },
+};
+
+// SUB reg/mem32, reg32     29 /r
+const SUB_RM32_REG32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x29, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
+    },
+};
+
+// SUB reg/mem64, reg64     29 /r
+const SUB_RM64_REG64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x29, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
+    },
+};
+
+// SUB reg8, reg/mem8       2A /r
+const SUB_REG8_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2a, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
+    },
+};
+
+// SUB reg16, reg/mem16     2B /r
+const SUB_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// SUB reg32, reg/mem32     2B /r
+const SUB_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// SUB reg64, reg/mem64     2B /r
+const SUB_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x2b, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "sub",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
+    },
+};
+
+// TEST AL, imm8    A8 ib
+const TEST_AL_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xa8, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
+    },
+};
+
+// TEST AX, imm16   A9 iw
+const TEST_AX_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xa9, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
+    },
+};
+
+// TEST EAX, imm32  A9 id
+const TEST_EAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xa9, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
+    },
+};
+
+// TEST RAX, imm32  A9 id
+const TEST_RAX_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xa9, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
+    },
+};
+
+// TEST reg/mem8, imm8      F6 /0 ib
+const TEST_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf6, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// TEST reg/mem16, imm16    F7 /0 iw
+const TEST_RM16_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
+    },
+};
+
+// TEST reg/mem32, imm32    F7 /0 id
+const TEST_RM32_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
+    },
+};
 
-// PUSH reg64   50 +rq
-const PUSH_R64: Instruction = Instruction {
+// TEST reg/mem64, imm32    F7 /0 id
+const TEST_RM64_IMM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x50, 0x00, 0x00], 1),
-        modrm: None,
+        opecode: SVec::from_raw([0xf7, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(0)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
+    },
+};
+
+// TEST reg/mem8, reg8      84 /r
+const TEST_RM8_REG8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x84, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
         imm: None,
-        opecode_register: Some(OpecodeRegisterRule::Rq),
-        default_operand_size: OperandSize::Oq,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "push",
-        operands: [Some(OperandType::R64), None],
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
     },
 };
 
-// PUSH reg/mem64   FF /6
-const PUSH_RM64: Instruction = Instruction {
+// TEST reg/mem16, reg16    85 /r
+const TEST_RM16_REG16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xff, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(6)),
+        opecode: SVec::from_raw([0x85, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
-        default_operand_size: OperandSize::Oq,
+        default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "push",
-        operands: [Some(OperandType::Rm64), None],
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
     },
 };
 
-// PUSH imm64   68 id
-const PUSH_IMM64: Instruction = Instruction {
+// TEST reg/mem32, reg32    85 /r
+const TEST_RM32_REG32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x68, 0x00, 0x00], 1),
-        modrm: None,
-        imm: Some(ImmRule::Id),
+        opecode: SVec::from_raw([0x85, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
         opecode_register: None,
-        default_operand_size: OperandSize::Oq,
+        default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "push",
-        operands: [Some(OperandType::Imm32), None],
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
     },
 };
 
-// MOV reg/mem64, reg64     89 /r
-const MOV_RM64_R64: Instruction = Instruction {
+// TEST reg/mem64, reg64    85 /r
+const TEST_RM64_REG64: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x89, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x85, 0x00, 0x00], 1),
         modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "mov",
-        operands: [Some(OperandType::Rm64), Some(OperandType::R64)],
+        mnemonic: "test",
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
     },
 };
 
-// MOV reg64, imm64     B8 +rq iq
-const MOV_R64_IMM64: Instruction = Instruction {
+// XOR AL, imm8     34 ib
+const XOR_AL_IMM8: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xb8, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x34, 0x00, 0x00], 1),
         modrm: None,
-        imm: Some(ImmRule::Iq),
-        opecode_register: Some(OpecodeRegisterRule::Rq),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "mov",
-        operands: [Some(OperandType::R64), Some(OperandType::Imm64)],
+        mnemonic: "xor",
+        operands: [Some(OperandType::Al), Some(OperandType::Imm8), None],
     },
 };
 
-// MOV reg/mem64, imm32     C7 /0 id
-const MOV_RM64_IMM32: Instruction = Instruction {
+// XOR AX, imm16    35 iw
+const XOR_AX_IMM16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xc7, 0x00, 0x00], 1),
-        modrm: Some(ModRmRule::Dight(0)),
-        imm: Some(ImmRule::Id),
+        opecode: SVec::from_raw([0x35, 0x00, 0x00], 1),
+        modrm: None,
+        imm: Some(ImmRule::Iw),
         opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "mov",
-        operands: [Some(OperandType::Rm64), Some(OperandType::Imm32)],
+        mnemonic: "xor",
+        operands: [Some(OperandType::Ax), Some(OperandType::Imm16), None],
     },
 };
 
-// MOV reg32, imm32     B8 +rd id
-const MOV_R32_IMM32: Instruction = Instruction {
+// XOR EAX, imm32   35 id
+const XOR_EAX_IMM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xb8, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x35, 0x00, 0x00], 1),
         modrm: None,
         imm: Some(ImmRule::Id),
-        opecode_register: Some(OpecodeRegisterRule::Rd),
+        opecode_register: None,
         default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "mov",
-        operands: [Some(OperandType::R32), Some(OperandType::Imm32)],
+        mnemonic: "xor",
+        operands: [Some(OperandType::Eax), Some(OperandType::Imm32), None],
     },
 };
 
-// POP reg64    58 +rq
-const POP_R64: Instruction = Instruction {
+// XOR RAX, imm32   35 id
+const XOR_RAX_IMM32: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0x58, 0x00, 0x00], 1),
+        opecode: SVec::from_raw([0x35, 0x00, 0x00], 1),
         modrm: None,
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rax), Some(OperandType::SImm32), None],
+    },
+};
+
+// XOR reg/mem8, imm8   80 /6 ib
+const XOR_RM8_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x80, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm8), Some(OperandType::Imm8), None],
+    },
+};
+
+// XOR reg/mem16, imm16     81 /6 iw
+const XOR_RM16_IMM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: Some(ImmRule::Iw),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm16), Some(OperandType::Imm16), None],
+    },
+};
+
+// XOR reg/mem32, imm32     81 /6 id
+const XOR_RM32_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm32), Some(OperandType::Imm32), None],
+    },
+};
+
+// XOR reg/mem64, imm32     81 /6 id
+const XOR_RM64_IMM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x81, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: Some(ImmRule::Id),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm32), None],
+    },
+};
+
+// XOR reg/mem16, imm8      83 /6 ib
+const XOR_RM16_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm16), Some(OperandType::SImm8), None],
+    },
+};
+
+// XOR reg/mem32, imm8      83 /6 ib
+const XOR_RM32_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm32), Some(OperandType::SImm8), None],
+    },
+};
+
+// XOR reg/mem64, imm8      83 /6 ib
+const XOR_RM64_IMM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x83, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::Dight(6)),
+        imm: Some(ImmRule::Ib),
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm64), Some(OperandType::SImm8), None],
+    },
+};
+
+// XOR reg/mem8, reg8       30 /r
+const XOR_RM8_REG8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x30, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
         imm: None,
-        opecode_register: Some(OpecodeRegisterRule::Rq),
-        default_operand_size: OperandSize::Oq,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "pop",
-        operands: [Some(OperandType::R64), None],
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm8), Some(OperandType::R8), None],
     },
 };
 
-// C3 RET
-const NEAR_RET: Instruction = Instruction {
+// XOR reg/mem16, reg16     31 /r
+const XOR_RM16_REG16: Instruction = Instruction {
     encoding: EncodingRule {
-        opecode: SVec::from_raw([0xc3, 0x00, 0x00], 1),
-        modrm: None,
+        opecode: SVec::from_raw([0x31, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
         imm: None,
         opecode_register: None,
-        default_operand_size: OperandSize::Oq,
+        default_operand_size: OperandSize::Od,
     },
     expression: Expression {
-        mnemonic: "ret",
-        operands: [None, None],
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm16), Some(OperandType::R16), None],
+    },
+};
+
+// XOR reg/mem32, reg32     31 /r
+const XOR_RM32_REG32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x31, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm32), Some(OperandType::R32), None],
+    },
+};
+
+// XOR reg/mem64, reg64     31 /r
+const XOR_RM64_REG64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x31, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::Rm64), Some(OperandType::R64), None],
+    },
+};
+
+// XOR reg8, reg/mem8       32 /r
+const XOR_REG8_RM8: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x32, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::R8), Some(OperandType::Rm8), None],
+    },
+};
+
+// XOR reg16, reg/mem16     33 /r
+const XOR_REG16_RM16: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x33, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::R16), Some(OperandType::Rm16), None],
+    },
+};
+
+// XOR reg32, reg/mem32     33 /r
+const XOR_REG32_RM32: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x33, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::R32), Some(OperandType::Rm32), None],
+    },
+};
+
+// XOR reg64, reg/mem64     33 /r
+const XOR_REG64_RM64: Instruction = Instruction {
+    encoding: EncodingRule {
+        opecode: SVec::from_raw([0x33, 0x00, 0x00], 1),
+        modrm: Some(ModRmRule::R),
+        imm: None,
+        opecode_register: None,
+        default_operand_size: OperandSize::Od,
+    },
+    expression: Expression {
+        mnemonic: "xor",
+        operands: [Some(OperandType::R64), Some(OperandType::Rm64), None],
     },
 };
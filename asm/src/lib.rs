@@ -3,12 +3,32 @@ pub mod assembler;
 
 pub use assembler::*;
 
+/// Errors of assembly
+pub mod error;
+
+pub use error::{AsmError, AsmWarning, Diagnostic, Renderer, Span, Style};
+
+/// Preprocessor for macros
+pub mod preprocessor;
+
 /// Simple parser for assembly
 pub mod parser;
 
+/// Symbol table
+pub mod symbol;
+
+/// Expression evaluator
+pub mod expression;
+
+/// Sections of assembled code
+pub mod section;
+
 /// Line information of assembly
 pub mod line;
 
+/// Front ends of assembly syntax
+pub mod syntax;
+
 /// Information of instructions
 pub mod instruction;
 
@@ -1,13 +1,23 @@
 use crate::{
-    functions::{is_keyword, parse_rm, Relocation},
-    instruction::{Instruction, OperandType, INSTRUCTION_LIST},
+    functions::{parse_rm, split_mnemonic, split_operands, split_pseudo_prefix, Relocation, RmRef},
+    instruction::{Instruction, OperandType, INSTRUCTION_LIST, MAX_OPERANDS},
     register::Register,
 };
-use util::functions::{result_to_option, stoi};
+use std::{cell::RefCell, str::FromStr};
+use util::functions::result_to_option;
 
 /// Methods related to machine code encoding
 pub mod encode;
 
+/// Methods related to assembler commands
+pub mod command;
+
+thread_local! {
+    /// Instruction which matched the last line
+    /// Encoding one line looks up its instruction for each field
+    static LAST_MATCH: RefCell<Option<(String, Option<Instruction>)>> = const { RefCell::new(None) };
+}
+
 /// Assembly line information
 #[derive(Clone, Copy, Debug)]
 pub enum Line<'a> {
@@ -18,22 +28,66 @@ pub enum Line<'a> {
     Unknown(&'a str),
 }
 
+/// Size of relative operands and displacements selected by pseudo prefix like `{disp8} jmp label`
+/// Instructions without pseudo prefix use rel32 and disp32 for labels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DispSize {
+    Disp8,
+    Disp32,
+}
+
+impl DispSize {
+    /// Get type of relative operands in this size
+    pub fn relative_operand(self) -> OperandType {
+        match self {
+            DispSize::Disp8 => OperandType::Rel8,
+            DispSize::Disp32 => OperandType::Rel32,
+        }
+    }
+
+    /// If relative operands of instruction are in this size
+    pub fn match_with(self, instruction: &Instruction) -> bool {
+        instruction
+            .expression()
+            .operands()
+            .iter()
+            .flatten()
+            .filter(|operand| operand.is_relative())
+            .all(|operand| *operand == self.relative_operand())
+    }
+}
+
+impl FromStr for DispSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disp8" => Ok(DispSize::Disp8),
+            "disp32" => Ok(DispSize::Disp32),
+            _ => Err("unknown pseudo prefix : ".to_string() + s),
+        }
+    }
+}
+
 impl<'a> Line<'a> {
     /// Split instruction and return mnemonic and operands
-    /// (mnemonic, operand1, operand2)
-    pub fn split_instruction(self) -> Option<(&'a str, [Option<&'a str>; 2])> {
+    pub fn split_instruction(self) -> Option<(&'a str, [Option<&'a str>; MAX_OPERANDS])> {
         if let Line::Instruction(s) = self {
-            let mut s_split = s.trim().split(' ');
-
-            let mnemonic = s_split.next().expect("unknown error");
-            let operand1 = s_split.next();
-            let operand2 = s_split.next();
+            let (prefix, s) = split_pseudo_prefix(s);
+            if prefix.is_some_and(|p| p.parse::<DispSize>().is_err()) {
+                return None;
+            }
+            let (mnemonic, operands) = split_mnemonic(s);
 
-            if s_split.next().is_none() {
-                Some((mnemonic, [operand1, operand2]))
-            } else {
-                None
+            let operands = split_operands(operands);
+            if MAX_OPERANDS < operands.len() {
+                return None;
+            }
+            let mut array = [None; MAX_OPERANDS];
+            for (slot, operand) in array.iter_mut().zip(operands) {
+                *slot = Some(operand);
             }
+            Some((mnemonic, array))
         } else {
             None
         }
@@ -53,18 +107,46 @@ impl<'a> Line<'a> {
     }
 
     /// Get operands
-    pub fn operands(self) -> Option<[Option<&'a str>; 2]> {
+    pub fn operands(self) -> Option<[Option<&'a str>; MAX_OPERANDS]> {
         Some(self.split_instruction()?.1)
     }
 
+    /// Get size selected by pseudo prefix
+    pub fn disp_size(self) -> Option<DispSize> {
+        match self {
+            Line::Instruction(s) => result_to_option(split_pseudo_prefix(s).0?.parse()),
+            _ => None,
+        }
+    }
+
     /// Get instruction information
+    /// The first instruction which matches wins, and pseudo prefix excludes other sizes
     pub fn get_instruction(self) -> Option<Instruction> {
-        for i in INSTRUCTION_LIST {
-            if i.match_with(&self) {
-                return Some(*i);
-            }
+        let Line::Instruction(s) = self else {
+            return None;
+        };
+        let last = LAST_MATCH.with_borrow(|last| {
+            last.as_ref()
+                .filter(|(line, _)| line == s)
+                .map(|(_, instruction)| *instruction)
+        });
+        if let Some(instruction) = last {
+            return instruction;
         }
-        None
+        let instruction = self.match_instruction();
+        LAST_MATCH.set(Some((s.to_string(), instruction)));
+        instruction
+    }
+
+    fn match_instruction(self) -> Option<Instruction> {
+        // Mnemonic is compared first, since matching operands is slow
+        let mnemonic = self.mnemonic()?;
+        let disp_size = self.disp_size();
+        INSTRUCTION_LIST
+            .iter()
+            .filter(|i| i.mnemonic() == mnemonic)
+            .find(|i| i.match_with(&self) && disp_size.is_none_or(|size| size.match_with(i)))
+            .copied()
     }
 
     fn get_operand_by_type(self, operand_type: OperandType) -> Option<&'a str> {
@@ -83,13 +165,12 @@ impl<'a> Line<'a> {
             .or_else(|| {
                 self.get_operand_by_type(OperandType::R32)
                     .or_else(|| self.get_operand_by_type(OperandType::R64))
-            })
-            .expect("invalid operation");
+            })?;
         result_to_option(expression.parse())
     }
 
     /// Get rm refering operand
-    pub fn rm_ref_operand(self) -> Option<(Relocation<'a, i32>, Register, Option<(Register, u8)>)> {
+    pub fn rm_ref_operand(self) -> Option<RmRef<'a>> {
         let (operand, address_size) = self
             .get_operand_by_type(OperandType::Rm8)
             .map(|t| (t, 'b'))
@@ -115,8 +196,7 @@ impl<'a> Line<'a> {
             .get_operand_by_type(OperandType::Rm8)
             .or_else(|| self.get_operand_by_type(OperandType::Rm16))
             .or_else(|| self.get_operand_by_type(OperandType::Rm32))
-            .or_else(|| self.get_operand_by_type(OperandType::Rm64))
-            .expect("invalid operation");
+            .or_else(|| self.get_operand_by_type(OperandType::Rm64))?;
 
         result_to_option(operand.parse())
     }
@@ -128,16 +208,11 @@ impl<'a> Line<'a> {
             .or_else(|| self.get_operand_by_type(OperandType::Imm16))
             .or_else(|| self.get_operand_by_type(OperandType::Imm32))
             .or_else(|| self.get_operand_by_type(OperandType::Imm64))
+            .or_else(|| self.get_operand_by_type(OperandType::SImm8))
+            .or_else(|| self.get_operand_by_type(OperandType::SImm32))
             .or_else(|| self.get_operand_by_type(OperandType::Rel8))
             .or_else(|| self.get_operand_by_type(OperandType::Rel16))
-            .or_else(|| self.get_operand_by_type(OperandType::Rel32))
-            .expect("invalid input");
-        if let Some(n) = stoi(operand) {
-            Some(Relocation::Value(n))
-        } else if is_keyword(operand) {
-            Some(Relocation::Label(operand))
-        } else {
-            None
-        }
+            .or_else(|| self.get_operand_by_type(OperandType::Rel32))?;
+        Relocation::parse(operand)
     }
 }
@@ -0,0 +1,298 @@
+use super::Line;
+use crate::{
+    functions::{parse_string_literal, split_arguments, Relocation},
+    section::{Section, MAX_IMAGE_SIZE},
+    symbol::{SymbolKind, SymbolTable},
+};
+
+impl<'a> Line<'a> {
+    /// Split assembler command and return name and arguments
+    /// (name, arguments)
+    pub fn split_command(self) -> Option<(&'a str, Vec<&'a str>)> {
+        if let Line::AsmCommand(s) = self {
+            let s = s.trim().strip_prefix('.')?;
+            let (name, arguments) = match s.split_once(|c: char| c.is_ascii_whitespace()) {
+                Some((name, arguments)) => (name, arguments),
+                None => (s, ""),
+            };
+            Some((name, split_arguments(arguments)))
+        } else {
+            None
+        }
+    }
+
+    /// Get assembler command name
+    pub fn command_name(self) -> Option<&'a str> {
+        Some(self.split_command()?.0)
+    }
+
+    /// Get assembler command arguments
+    pub fn command_arguments(self) -> Option<Vec<&'a str>> {
+        Some(self.split_command()?.1)
+    }
+
+    /// Get section name if this is a section command
+    /// Flags and type like `.section .text,"ax",@progbits` are ignored
+    pub fn section_name(self) -> Option<&'a str> {
+        let (name, arguments) = self.split_command()?;
+
+        match (name, arguments.as_slice()) {
+            ("text", []) => Some(".text"),
+            ("data", []) => Some(".data"),
+            ("rodata", []) => Some(".rodata"),
+            ("bss", []) => Some(".bss"),
+            ("section", [section, rest @ ..])
+                if section.starts_with('.') && rest.first().is_none_or(|f| f.starts_with('"')) =>
+            {
+                Some(section)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get name, expression and kind if this is a constant definition command
+    pub fn constant_definition(self) -> Option<(&'a str, &'a str, SymbolKind)> {
+        let (name, arguments) = self.split_command()?;
+
+        let kind = match name {
+            "equ" => SymbolKind::Constant,
+            "set" => SymbolKind::Variable,
+            _ => return None,
+        };
+        match arguments.as_slice() {
+            [symbol, expr] => Some((symbol, expr, kind)),
+            _ => None,
+        }
+    }
+
+    /// Get alignment required by alignment command
+    pub fn command_align(self, symbols: &SymbolTable) -> Option<usize> {
+        let (name, arguments) = self.split_command()?;
+        let value = symbols.evaluate(arguments.first()?).ok()?;
+
+        let align = match name {
+            "align" | "balign" => usize::try_from(value).ok()?,
+            "p2align" if (0..usize::BITS as i128).contains(&value) => 1 << value,
+            _ => return None,
+        };
+        if align.is_power_of_two() {
+            Some(align)
+        } else {
+            None
+        }
+    }
+
+    /// Get length and fill byte of padding emitted by padding command
+    /// Fill byte is None if it is omitted
+    fn padding(self, symbols: &SymbolTable, section: &Section) -> Option<(usize, Option<u8>)> {
+        let (name, arguments) = self.split_command()?;
+
+        match name {
+            "align" | "balign" | "p2align" => {
+                if 3 < arguments.len() {
+                    return None;
+                }
+                let align = self.command_align(symbols)?;
+                let fill = optional_argument(&arguments, 1, |s| fill_value(symbols, s))?;
+                let max = optional_argument(&arguments, 2, |s| integer::<usize>(symbols, s))?;
+
+                let len = section.size().next_multiple_of(align) - section.size();
+                match max {
+                    Some(max) if max < len => Some((0, fill)),
+                    _ => Some((len, fill)),
+                }
+            }
+            "zero" => match arguments.as_slice() {
+                [len] => Some((integer(symbols, len)?, Some(0))),
+                _ => None,
+            },
+            "skip" => {
+                if arguments.is_empty() || 2 < arguments.len() {
+                    return None;
+                }
+                let len = integer(symbols, arguments[0])?;
+                let fill = optional_argument(&arguments, 1, |s| fill_value(symbols, s))?;
+                Some((len, Some(fill.unwrap_or(0))))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get repeat count, size and value of .fill command
+    fn fill(self, symbols: &SymbolTable) -> Option<(usize, usize, i128)> {
+        let (name, arguments) = self.split_command()?;
+        if name != "fill" || arguments.is_empty() || 3 < arguments.len() {
+            return None;
+        }
+
+        let repeat = integer(symbols, arguments[0])?;
+        let size = optional_argument(&arguments, 1, |s| integer(symbols, s))?.unwrap_or(1);
+        let value = optional_argument(&arguments, 2, |s| symbols.evaluate(s).ok())?.unwrap_or(0);
+        // repeat is bounded by itself since size can be 0
+        if 8 < size || MAX_IMAGE_SIZE < repeat {
+            return None;
+        }
+        Some((repeat, size, value))
+    }
+
+    /// Get length of bytes emitted by assembler command
+    pub fn command_len(self, symbols: &SymbolTable, section: &Section) -> Option<usize> {
+        let (name, arguments) = self.split_command()?;
+
+        if self.section_name().is_some() || self.constant_definition().is_some() {
+            Some(0)
+        } else if let Some((len, _)) = self.padding(symbols, section) {
+            Some(len)
+        } else if let Some((repeat, size, _)) = self.fill(symbols) {
+            repeat.checked_mul(size)
+        } else if let Some(size) = data_size(name) {
+            if arguments.is_empty() {
+                return None;
+            }
+            for argument in &arguments {
+                data_value(argument, size)?;
+            }
+            Some(size * arguments.len())
+        } else if let Some(terminator_len) = string_terminator_len(name) {
+            if arguments.is_empty() {
+                return None;
+            }
+            let mut len = 0;
+            for argument in arguments {
+                len += parse_string_literal(argument)?.len() + terminator_len;
+            }
+            Some(len)
+        } else {
+            None
+        }
+    }
+
+    /// Get bytes emitted by assembler command
+    pub fn command_data(self, symbols: &SymbolTable, section: &Section) -> Result<Vec<u8>, String> {
+        let (name, arguments) = self.split_command().ok_or("invalid assembler command")?;
+        let mut bytes = Vec::new();
+
+        if let Some((len, fill)) = self.padding(symbols, section) {
+            match fill {
+                Some(fill) => bytes.resize(len, fill),
+                None if section.flags().is_executable() => bytes = nop_padding(len),
+                None => bytes.resize(len, 0),
+            }
+        } else if let Some((repeat, size, value)) = self.fill(symbols) {
+            let value_u128 = i128::cast_unsigned(value);
+            let unit: Vec<u8> = (0..size).map(|i| (value_u128 >> (i * 8)) as u8).collect();
+            bytes = unit.repeat(repeat);
+        } else if let Some(size) = data_size(name) {
+            for argument in arguments {
+                // Labels are relocated to its offset from start of image
+                let value = data_value(argument, size)
+                    .ok_or_else(|| "invalid data : ".to_string() + argument)?
+                    .relocate_imm(symbols, 0)?;
+                if !value_fits(value, size) {
+                    return Err("data out of range : ".to_string() + argument);
+                }
+                let value_u128 = i128::cast_unsigned(value);
+                for i in 0..size {
+                    bytes.push((value_u128 >> (i * 8)) as u8);
+                }
+            }
+        } else if let Some(terminator_len) = string_terminator_len(name) {
+            for argument in arguments {
+                let string = parse_string_literal(argument)
+                    .ok_or_else(|| "invalid string : ".to_string() + argument)?;
+                bytes.extend_from_slice(&string);
+                bytes.resize(bytes.len() + terminator_len, 0);
+            }
+        } else {
+            return Err("unknown assembler command : .".to_string() + name);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Size of data emitted by data directive
+fn data_size(name: &str) -> Option<usize> {
+    match name {
+        "byte" => Some(1),
+        "word" | "short" => Some(2),
+        "dword" | "long" | "int" => Some(4),
+        "qword" | "quad" => Some(8),
+        _ => None,
+    }
+}
+
+/// Length of terminator appended by string directive
+fn string_terminator_len(name: &str) -> Option<usize> {
+    match name {
+        "ascii" => Some(0),
+        "asciz" => Some(1),
+        _ => None,
+    }
+}
+
+fn data_value(expr: &str, size: usize) -> Option<Relocation<'_, i128>> {
+    match Relocation::parse(expr)? {
+        Relocation::Value(value) if !value_fits(value, size) => None,
+        relocation => Some(relocation),
+    }
+}
+
+/// Get optional argument at index
+/// Returns Some(None) if the argument is omitted, and None if it is invalid
+fn optional_argument<T>(
+    arguments: &[&str],
+    index: usize,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Option<Option<T>> {
+    match arguments.get(index) {
+        None => Some(None),
+        Some(&"") => Some(None),
+        Some(s) => Some(Some(parse(s)?)),
+    }
+}
+
+fn fill_value(symbols: &SymbolTable, expr: &str) -> Option<u8> {
+    let value = symbols.evaluate(expr).ok()?;
+    if value_fits(value, 1) {
+        Some(value as u8)
+    } else {
+        None
+    }
+}
+
+/// Evaluate argument which must be known in the first pass
+fn integer<T: TryFrom<i128>>(symbols: &SymbolTable, expr: &str) -> Option<T> {
+    T::try_from(symbols.evaluate(expr).ok()?).ok()
+}
+
+/// Recommended multi-byte NOP sequences
+const NOPS: [&[u8]; 9] = [
+    &[0x90],
+    &[0x66, 0x90],
+    &[0x0f, 0x1f, 0x00],
+    &[0x0f, 0x1f, 0x40, 0x00],
+    &[0x0f, 0x1f, 0x44, 0x00, 0x00],
+    &[0x66, 0x0f, 0x1f, 0x44, 0x00, 0x00],
+    &[0x0f, 0x1f, 0x80, 0x00, 0x00, 0x00, 0x00],
+    &[0x0f, 0x1f, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x0f, 0x1f, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+];
+
+/// Get NOP padding which consists of the fewest NOP instructions
+fn nop_padding(mut len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    while len != 0 {
+        let nop = NOPS[len.min(NOPS.len()) - 1];
+        bytes.extend_from_slice(nop);
+        len -= nop.len();
+    }
+    bytes
+}
+
+fn value_fits(value: i128, size: usize) -> bool {
+    let bits = size as u32 * 8;
+    let min = -(1i128 << (bits - 1));
+    let max = (1i128 << bits) - 1;
+    min <= value && value <= max
+}
@@ -1,103 +1,121 @@
-use super::Line;
+use super::{DispSize, Line};
 use crate::{
-    assembler::Label,
     functions::Relocation,
     instruction::{ImmRule, ModRmRule, OperandSize, OperandType},
     register::{Register, RegisterCode},
+    symbol::SymbolTable,
 };
-use std::{cmp::max, mem::transmute};
 use util::svec::SVec;
 
+/// Error of lines which don't match any instruction
+const INVALID_OPERATION: &str = "invalid operation";
+
 impl<'a> Line<'a> {
     /// Get raw machine code
-    pub fn machine_code(self, labels: &[Label<'a>], offset: usize) -> Result<SVec<19, u8>, String> {
+    pub fn machine_code(
+        self,
+        symbols: &SymbolTable,
+        offset: usize,
+    ) -> Result<SVec<19, u8>, String> {
+        let too_long = || "machine code too long".to_string();
+
         let mut svec = SVec::new();
-        svec += self.legacy_prefix(); //1
-        svec += self.rex_prefix(); //1
-        svec += self.opecode(); //3
-        svec += self.modrm(); //1
-        svec += self.sib(); //1
-        svec += self.disp(labels, offset)?; //4
-        svec += self.imm(labels, offset)?; //8
+        svec.try_append(self.legacy_prefix()?)
+            .ok_or_else(too_long)?; //1
+        svec.try_append(self.rex_prefix()?).ok_or_else(too_long)?; //1
+        svec.try_append(self.opecode()?).ok_or_else(too_long)?; //3
+        svec.try_append(self.modrm()?).ok_or_else(too_long)?; //1
+        svec.try_append(self.sib()?).ok_or_else(too_long)?; //1
+        svec.try_append(self.disp(symbols, offset)?)
+            .ok_or_else(too_long)?; //4
+        svec.try_append(self.imm(symbols, offset)?)
+            .ok_or_else(too_long)?; //8
         Ok(svec)
     }
 
     /// Get raw machine code length
-    pub fn machine_code_len(self) -> usize {
+    pub fn machine_code_len(self) -> Result<usize, String> {
         let mut len = 0;
 
-        len += self.legacy_prefix_len();
-        len += self.rex_prefix_len();
-        len += self.opecode_len();
-        len += self.modrm_len();
-        len += self.sib_len();
-        len += self.disp_len();
-        len += self.imm_len();
+        len += self.legacy_prefix_len()?;
+        len += self.rex_prefix_len()?;
+        len += self.opecode_len()?;
+        len += self.modrm_len()?;
+        len += self.sib_len()?;
+        len += self.disp_len()?;
+        len += self.imm_len()?;
 
-        len
+        Ok(len)
     }
 
     /// Get opecode in raw machine code
-    pub fn opecode(self) -> SVec<3, u8> {
-        let instruction = self.get_instruction().expect("invalid operation");
+    pub fn opecode(self) -> Result<SVec<3, u8>, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
         let mut opecode = instruction.encoding().opecode();
 
         let opecode_len = opecode.len();
-        opecode[opecode_len - 1] += self
-            .opecode_register_code()
-            .or(Some((None, 0)))
-            .expect("unknown error")
-            .1;
+        let (_, register_code) = self.opecode_register_code()?.unwrap_or((None, 0));
+        let last = opecode
+            .get_mut(opecode_len.wrapping_sub(1))
+            .ok_or(INVALID_OPERATION)?;
+        *last = last.wrapping_add(register_code);
 
-        opecode
+        Ok(opecode)
     }
 
-    fn opecode_len(self) -> usize {
-        let instruction = self.get_instruction().expect("invalid operation");
+    fn opecode_len(self) -> Result<usize, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
         let opecode = instruction.encoding().opecode();
-        opecode.len()
+        Ok(opecode.len())
     }
 
-    fn opecode_register_code(self) -> Option<RegisterCode> {
-        let instruction = self.get_instruction().expect("invalid operation");
+    fn opecode_register_code(self) -> Result<Option<RegisterCode>, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
         let opecode_register_rule = instruction.encoding().opecode_register_rule();
 
         match opecode_register_rule {
-            Some(_) => Some(
-                self.register_operand()
-                    .expect("invalid operation")
-                    .register_code_for_opecode_register(),
-            ),
-            None => None,
+            None => Ok(None),
+            Some(_) => {
+                let register = self.register_operand().ok_or(INVALID_OPERATION)?;
+                Ok(Some(register_code(register)?))
+            }
         }
     }
 
-    fn modrm_register_regcode(self) -> RegisterCode {
-        let instruction = self.get_instruction().expect("invalid operation");
+    fn modrm_register_regcode(self) -> Result<RegisterCode, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
         let encoding = instruction.encoding();
 
         match encoding.modrm_rule() {
-            None => panic!("modrm field doesn't exist"),
+            None => Err("modrm field doesn't exist".to_string()),
             Some(ModRmRule::R) => {
-                let register = self.register_operand().expect("invalid operation");
-                register.register_code_for_opecode_register()
+                let register = self.register_operand().ok_or(INVALID_OPERATION)?;
+                register_code(register)
             }
-            Some(ModRmRule::Dight(i)) => (Some(false), i),
+            Some(ModRmRule::Dight(i)) => Ok((Some(false), i)),
         }
     }
 
     fn modrm_ref_base(self) -> Option<Register> {
         let (_, base, _) = self.rm_ref_operand()?;
-        Some(base)
+        base
+    }
+
+    /// If memory operand has only index like `disp[,index,scale]`
+    fn modrm_ref_has_no_base(self) -> bool {
+        matches!(self.rm_ref_operand(), Some((_, None, _)))
     }
 
-    fn modrm_base_regcode(self) -> RegisterCode {
+    fn modrm_base_regcode(self) -> Result<RegisterCode, String> {
         if let Some(r) = self.rm_register_operand() {
-            r.register_code_for_opecode_register()
+            register_code(r)
+        } else if self.modrm_ref_has_no_base() {
+            // no base is encoded as base 101 in SIB with mod 00
+            Ok((Some(false), 0b101))
         } else {
             self.modrm_ref_base()
-                .expect("invalid operation")
-                .register_code_for_rm_ref_base()
+                .and_then(Register::register_code_for_rm_ref_base)
+                .ok_or_else(|| INVALID_OPERATION.to_string())
         }
     }
 
@@ -109,29 +127,37 @@ impl<'a> Line<'a> {
         }
     }
 
-    fn modrm_index_regcode(self) -> Option<RegisterCode> {
-        Some(self.modrm_ref_index()?.register_code_for_rm_ref_index())
+    fn modrm_index_regcode(self) -> Result<Option<RegisterCode>, String> {
+        match self.modrm_ref_index() {
+            None => Ok(None),
+            Some(index) => index
+                .register_code_for_rm_ref_index()
+                .map(Some)
+                .ok_or_else(|| format!("invalid index register : {:?}", index).to_lowercase()),
+        }
     }
 
     fn modrm_scale(self) -> Option<u8> {
-        if let (_, _, Some((_, scale))) = self.rm_ref_operand().expect("invalid operation") {
-            Some(scale)
-        } else {
-            None
-        }
+        let (_, _, index) = self.rm_ref_operand()?;
+        index.map(|(_, scale)| scale)
     }
 
-    fn modrm_disp(self) -> Relocation<'a, i32> {
-        let (disp, _, _) = self.rm_ref_operand().expect("invalid operation");
-        disp
+    fn modrm_disp(self) -> Result<Relocation<'a, i32>, String> {
+        let (disp, _, _) = self.rm_ref_operand().ok_or(INVALID_OPERATION)?;
+        Ok(disp)
     }
 
-    fn modrm_mode(self) -> u8 {
+    fn modrm_mode(self) -> Result<u8, String> {
+        if self.modrm_ref_has_no_base() {
+            return Ok(0b00);
+        }
         let modrm_ref_base = self.modrm_ref_base();
-        match modrm_ref_base {
+        Ok(match modrm_ref_base {
             Some(Register::Rip) => 0b00,
+            Some(_) if self.disp_size() == Some(DispSize::Disp8) => 0b01,
+            Some(_) if self.disp_size() == Some(DispSize::Disp32) => 0b10,
             Some(r) => {
-                let modrm_disp = self.modrm_disp();
+                let modrm_disp = self.modrm_disp()?;
                 let disp_is_8bit;
                 let disp_isnt_exist;
                 if let Relocation::Value(d) = modrm_disp {
@@ -154,26 +180,28 @@ impl<'a> Line<'a> {
                 }
             }
             None => 0b11,
-        }
+        })
     }
 
-    fn disp_len(self) -> usize {
-        if self.modrm_exist() {
-            match self.modrm_mode() {
-                0b00 | 0b11 => 0,
+    fn disp_len(self) -> Result<usize, String> {
+        if self.modrm_exist()? {
+            if self.modrm_ref_base() == Some(Register::Rip) || self.modrm_ref_has_no_base() {
+                return Ok(4);
+            }
+            Ok(match self.modrm_mode()? {
                 0b01 => 1,
                 0b10 => 4,
-                _ => panic!("invalid output"),
-            }
+                _ => 0,
+            })
         } else {
-            0
+            Ok(0)
         }
     }
 
-    fn modrm_exist(self) -> bool {
-        let instruction = self.get_instruction().expect("invalid operation");
+    fn modrm_exist(self) -> Result<bool, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
         let encoding = instruction.encoding();
-        encoding.modrm_rule().is_some()
+        Ok(encoding.modrm_rule().is_some())
     }
 
     fn sib_exist(self) -> bool {
@@ -183,37 +211,34 @@ impl<'a> Line<'a> {
             || modrm_ref_base == Some(Register::R12)
     }
 
-    pub fn modrm(self) -> SVec<1, u8> {
-        if self.modrm_exist() {
-            let mode = self.modrm_mode();
-            let (_, reg) = self.modrm_register_regcode();
+    pub fn modrm(self) -> Result<SVec<1, u8>, String> {
+        if self.modrm_exist()? {
+            let mode = self.modrm_mode()?;
+            let (_, reg) = self.modrm_register_regcode()?;
             let base = if self.sib_exist() {
                 0b100
             } else {
-                self.modrm_base_regcode().1
+                self.modrm_base_regcode()?.1
             };
             let modrm = (mode << 6) | (reg << 3) | base;
-            SVec::from([modrm])
+            Ok(SVec::from([modrm]))
         } else {
-            return SVec::new();
+            Ok(SVec::new())
         }
     }
 
-    fn modrm_len(self) -> usize {
-        if self.modrm_exist() {
-            1
+    fn modrm_len(self) -> Result<usize, String> {
+        if self.modrm_exist()? {
+            Ok(1)
         } else {
-            0
+            Ok(0)
         }
     }
 
-    pub fn sib(self) -> SVec<1, u8> {
+    pub fn sib(self) -> Result<SVec<1, u8>, String> {
         if self.sib_exist() {
-            let (_, base) = self.modrm_base_regcode();
-            let (_, index) = self
-                .modrm_index_regcode()
-                .or(Some((None, 0b100)))
-                .expect("invalid operation");
+            let (_, base) = self.modrm_base_regcode()?;
+            let (_, index) = self.modrm_index_regcode()?.unwrap_or((None, 0b100));
             let scale: u8 = match self.modrm_scale() {
                 Some(1) => 0b00,
                 Some(2) => 0b01,
@@ -223,95 +248,100 @@ impl<'a> Line<'a> {
             };
 
             let sib = (scale << 6) | (index << 3) | base;
-            SVec::from([sib])
+            Ok(SVec::from([sib]))
         } else {
-            SVec::new()
+            Ok(SVec::new())
         }
     }
 
-    fn sib_len(self) -> usize {
+    fn sib_len(self) -> Result<usize, String> {
         if self.sib_exist() {
-            1
+            Ok(1)
         } else {
-            0
+            Ok(0)
         }
     }
 
-    fn rex_prefix_is_required(self) -> bool {
-        let instruction = self.get_instruction().expect("invalid operation");
+    fn rex_prefix_is_required(self) -> Result<bool, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
         let encoding = instruction.encoding();
         let default_operand_size = encoding.default_operand_size();
-        assert!(OperandSize::Od <= default_operand_size);
+        if default_operand_size < OperandSize::Od {
+            return Err("invalid default operand size".to_string());
+        }
 
-        if let Some(operand_size) = self.operand_size() {
-            default_operand_size < operand_size
+        if let Some(operand_size) = self.operand_size()? {
+            Ok(default_operand_size < operand_size)
         } else {
-            false
+            Ok(false)
         }
     }
 
-    fn prefix_x66_is_required(self) -> bool {
-        if let Some(operand_size) = self.operand_size() {
-            operand_size == OperandSize::Ob
+    fn prefix_x66_is_required(self) -> Result<bool, String> {
+        if let Some(operand_size) = self.operand_size()? {
+            Ok(operand_size == OperandSize::Ow)
         } else {
-            false
+            Ok(false)
         }
     }
 
-    fn operand_size(self) -> Option<OperandSize> {
-        let instruction = self.get_instruction().expect("invalid operation");
+    fn operand_size(self) -> Result<Option<OperandSize>, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
         let expression = instruction.expression();
-        let operands_types = expression.operands();
-        max(
-            operands_types[0].map(OperandType::size),
-            operands_types[1].map(OperandType::size),
-        )
+        Ok(expression
+            .operands()
+            .iter()
+            .flatten()
+            .copied()
+            .map(OperandType::size)
+            .max())
     }
 
     /// Get legacy prefix in raw machine code
-    pub fn legacy_prefix(self) -> SVec<1, u8> {
+    pub fn legacy_prefix(self) -> Result<SVec<1, u8>, String> {
         let mut svec = SVec::new();
-        if self.prefix_x66_is_required() {
+        if self.prefix_x66_is_required()? {
             svec.push(0x66);
         }
-        svec
+        Ok(svec)
     }
 
-    fn legacy_prefix_len(self) -> usize {
-        self.legacy_prefix().len()
+    fn legacy_prefix_len(self) -> Result<usize, String> {
+        Ok(self.legacy_prefix()?.len())
     }
 
     /// Get rex prefix in raw machine code
-    pub fn rex_prefix(self) -> SVec<1, u8> {
+    pub fn rex_prefix(self) -> Result<SVec<1, u8>, String> {
         let mut rex_w = false;
         let mut rex_r = false;
         let mut rex_x = false;
         let mut rex_b = false;
 
-        if self.rex_prefix_is_required() {
+        if self.rex_prefix_is_required()? {
             rex_w = true;
         }
 
-        let modrm_exist = self.modrm_exist();
+        let modrm_exist = self.modrm_exist()?;
 
-        let opecode_register_code = self.opecode_register_code();
+        let opecode_register_code = self.opecode_register_code()?;
         let modrm_register_regcode = if modrm_exist {
-            Some(self.modrm_register_regcode())
+            Some(self.modrm_register_regcode()?)
         } else {
             None
         };
         if let Some((Some(true), _)) = modrm_register_regcode {
             rex_r = true;
         }
+        // Register embedded in opecode is extended by REX.B
         if let Some((Some(true), _)) = opecode_register_code {
-            rex_r = true;
+            rex_b = true;
         }
         let modrm_base_regcode = if modrm_exist {
-            Some(self.modrm_base_regcode())
+            Some(self.modrm_base_regcode()?)
         } else {
             None
         };
-        let modrm_index_regcode = self.modrm_index_regcode();
+        let modrm_index_regcode = self.modrm_index_regcode()?;
         if let Some((Some(true), _)) = modrm_base_regcode {
             rex_b = true;
         }
@@ -319,14 +349,24 @@ impl<'a> Line<'a> {
             rex_x = true;
         }
 
+        let rex_is_required = [self.register_operand(), self.rm_register_operand()]
+            .into_iter()
+            .flatten()
+            .any(Register::requires_rex_prefix);
+
         let mut rex_prefix = SVec::new();
 
-        if rex_w || rex_r || rex_x || rex_b {
-            if let Some((None, _)) = opecode_register_code {
-                panic!("unknown error");
-            }
-            if let Some((None, _)) = modrm_register_regcode {
-                panic!("unknown error");
+        if rex_w || rex_r || rex_x || rex_b || rex_is_required {
+            // ah, ch, dh and bh can't be encoded with rex prefix
+            if [
+                opecode_register_code,
+                modrm_register_regcode,
+                modrm_base_regcode,
+            ]
+            .iter()
+            .any(|code| matches!(code, Some((None, _))))
+            {
+                return Err("ah, ch, dh and bh can't be used with rex prefix".to_string());
             }
 
             rex_prefix.push(0x40);
@@ -342,24 +382,57 @@ impl<'a> Line<'a> {
             if rex_b {
                 rex_prefix[0] |= 0x01;
             }
-            rex_prefix
+            Ok(rex_prefix)
         } else {
-            rex_prefix
+            Ok(rex_prefix)
         }
     }
 
-    fn rex_prefix_len(self) -> usize {
-        self.rex_prefix().len()
+    fn rex_prefix_len(self) -> Result<usize, String> {
+        Ok(self.rex_prefix()?.len())
+    }
+
+    /// If immediate must be in signed range since it is relative or sign-extended
+    fn has_signed_imm(self) -> Result<bool, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
+        Ok(instruction
+            .expression()
+            .operands()
+            .iter()
+            .flatten()
+            .any(|operand| operand.is_signed()))
     }
 
-    fn imm_len(self) -> usize {
+    fn has_relative_operand(self) -> Result<bool, String> {
+        let instruction = self.get_instruction().ok_or(INVALID_OPERATION)?;
+        Ok(instruction
+            .expression()
+            .operands()
+            .iter()
+            .flatten()
+            .any(|operand| operand.is_relative()))
+    }
+
+    /// If relative operand or displacement may be shortened by pseudo prefix `{disp8}`
+    /// Displacements which are numbers are already short if they fit
+    pub fn is_relaxable(self) -> bool {
+        let label_disp = match self.rm_ref_operand() {
+            Some((disp, base, _)) => {
+                !matches!(disp, Relocation::Value(_)) && base.is_some_and(|b| b != Register::Rip)
+            }
+            None => false,
+        };
+        label_disp || self.has_relative_operand() == Ok(true)
+    }
+
+    fn imm_len(self) -> Result<usize, String> {
         let imm_rule = self
             .get_instruction()
-            .expect("invalid operation")
+            .ok_or(INVALID_OPERATION)?
             .encoding()
             .imm_rule();
 
-        match imm_rule {
+        Ok(match imm_rule {
             None => 0,
             Some(i) => match i {
                 ImmRule::Ib => 1,
@@ -367,39 +440,77 @@ impl<'a> Line<'a> {
                 ImmRule::Id => 4,
                 ImmRule::Iq => 8,
             },
-        }
+        })
     }
 
     /// Get Imm in raw machine code
-    pub fn imm(self, labels: &[Label<'a>], offset: usize) -> Result<SVec<8, u8>, String> {
+    pub fn imm(self, symbols: &SymbolTable, offset: usize) -> Result<SVec<8, u8>, String> {
         let imm_rule = self
             .get_instruction()
-            .expect("invalid operation")
+            .ok_or(INVALID_OPERATION)?
             .encoding()
             .imm_rule();
         match imm_rule {
             None => Ok(SVec::new()),
             Some(_) => {
+                // Relative operands are relative to next instruction
+                let relative = self.has_relative_operand()?;
+                let base = if relative {
+                    offset + self.machine_code_len()?
+                } else {
+                    0
+                };
                 let imm: i128 = self
                     .imm_operand()
-                    .expect("invalid operation")
-                    .relocate_imm(labels, offset + self.machine_code_len())?;
-                let imm_usize: u128 = unsafe { transmute::<i128, u128>(imm) };
-                let imm_len = self.imm_len();
+                    .ok_or(INVALID_OPERATION)?
+                    .relocate_imm(symbols, base)?;
+                let imm_len = self.imm_len()?;
+                if !imm_fits(imm, imm_len, self.has_signed_imm()?) {
+                    return Err("immediate out of range".to_string());
+                }
+                let imm_usize: u128 = i128::cast_unsigned(imm);
                 Ok(SVec::from_value(imm_usize, imm_len))
             }
         }
     }
 
     /// Get Disp in raw machine code
-    pub fn disp(self, labels: &[Label<'a>], offset: usize) -> Result<SVec<4, u8>, String> {
-        let disp_len = self.disp_len();
+    pub fn disp(self, symbols: &SymbolTable, offset: usize) -> Result<SVec<4, u8>, String> {
+        let disp_len = self.disp_len()?;
         if disp_len == 0 {
             Ok(SVec::new())
         } else {
-            let disp = self.modrm_disp().relocate_disp(labels, offset)?;
-            let disp_usize = unsafe { transmute::<i128, u128>(disp as i128) };
+            // Only rip relative displacements are relative to next instruction
+            let base = if self.modrm_ref_base() == Some(Register::Rip) {
+                offset + self.machine_code_len()?
+            } else {
+                0
+            };
+            let disp = self.modrm_disp()?.relocate_disp(symbols, base)?;
+            if disp_len == 1 && i8::try_from(disp).is_err() {
+                return Err("displacement out of range".to_string());
+            }
+            let disp_usize = i128::cast_unsigned(disp as i128);
             Ok(SVec::from_value(disp_usize, disp_len))
         }
     }
 }
+
+/// Get register code of register operand
+fn register_code(register: Register) -> Result<RegisterCode, String> {
+    register
+        .register_code_for_opecode_register()
+        .ok_or_else(|| format!("invalid register : {:?}", register).to_lowercase())
+}
+
+/// If imm fits in len bytes
+/// Relative and sign-extended values must be signed, and the others can also be unsigned
+fn imm_fits(imm: i128, len: usize, signed: bool) -> bool {
+    let bits = len as u32 * 8;
+    if i128::BITS <= bits {
+        return true;
+    }
+    let min = -(1 << (bits - 1));
+    let max = if signed { 1 << (bits - 1) } else { 1 << bits };
+    min <= imm && imm < max
+}
@@ -1,17 +1,20 @@
 use crate::{
-    functions::{is_asm_command, is_instruction, is_label},
+    functions::{comment_start, is_asm_command, is_instruction, is_label, split_statements},
     line::Line,
+    syntax::Syntax,
 };
-use std::{iter::Iterator, str::Lines};
+use std::{collections::VecDeque, iter::Iterator, str::Lines};
 
 /// Simple parser for assembly
+/// Comments of Intel syntax are removed from each line before it is parsed
+/// Lines which contain several statements are split into several Lines
 /// # Usage
 /// ```
 /// use asm::parser::Parser;
 /// let source = "
 ///     .text
-///     main:
-///     push rbp
+///     main: // entry point
+///     push rbp ; save frame pointer
 ///     mov rbp, rsp
 ///
 ///     mov rax, 0
@@ -28,6 +31,9 @@ use std::{iter::Iterator, str::Lines};
 #[derive(Clone, Debug)]
 pub struct Parser<'a> {
     lines: Lines<'a>,
+    in_comment: bool,
+    statements: VecDeque<Line<'a>>,
+    line_number: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -35,28 +41,109 @@ impl<'a> Parser<'a> {
     pub fn new(source: &'a str) -> Self {
         Parser {
             lines: source.lines(),
+            in_comment: false,
+            statements: VecDeque::new(),
+            line_number: 0,
         }
     }
+
+    /// Get line number of the last Line returned by next
+    /// Lines split from the same source line have the same line number
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
 }
 
 impl<'a> Iterator for Parser<'a> {
     type Item = Line<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let line = self.lines.next()?.trim();
+        if let Some(statement) = self.statements.pop_front() {
+            return Some(statement);
+        }
+        let line = self.lines.next()?;
+        self.line_number += 1;
 
-        if line.is_empty() {
-            return Some(Line::None);
+        let (code, joined, in_comment) = code_of_line(line, self.in_comment);
+        self.in_comment = in_comment;
+        if joined {
+            // Code on both sides of a block comment can't be borrowed as one statement
+            self.statements.push_back(Line::Unknown(code.trim()));
+        } else {
+            self.statements.extend(parse_statements(code));
         }
-        if is_label(line) {
-            return Some(Line::Label(&line[..line.len() - 1]));
+        Some(self.statements.pop_front().unwrap_or(Line::None))
+    }
+}
+
+/// Get code in line without comments before and after it
+/// Returns the code, if it has block comments inside, and if a block comment continues to the next line
+fn code_of_line(line: &str, in_comment: bool) -> (&str, bool, bool) {
+    let mut offset = 0;
+    if in_comment {
+        match line.find("*/") {
+            Some(end) => offset = end + 2,
+            None => return ("", false, true),
         }
-        if is_asm_command(line) {
-            return Some(Line::AsmCommand(line));
+    }
+
+    // Ranges of code between comments
+    let mut pieces = Vec::new();
+    let mut in_comment = false;
+    loop {
+        let rest = &line[offset..];
+        let Some(start) = comment_start(rest, Syntax::Intel) else {
+            pieces.push(offset..line.len());
+            break;
+        };
+        pieces.push(offset..offset + start);
+        if !rest[start..].starts_with("/*") {
+            break;
         }
-        if is_instruction(line) {
-            return Some(Line::Instruction(line));
+        match rest[start + 2..].find("*/") {
+            Some(end) => offset += start + 2 + end + 2,
+            None => {
+                in_comment = true;
+                break;
+            }
         }
-        Some(Line::Unknown(line))
     }
+
+    pieces.retain(|piece| !line[piece.clone()].trim().is_empty());
+    let code = match (pieces.first(), pieces.last()) {
+        (Some(first), Some(last)) => &line[first.start..last.end],
+        _ => "",
+    };
+    (code, 1 < pieces.len(), in_comment)
+}
+
+/// Parse a line without comments which may contain several statements
+/// # Example
+/// ```
+/// use asm::{line::Line, parser::parse_statements};
+/// let lines = parse_statements("loop: dec rcx @ jnz loop");
+///
+/// assert!(matches!(lines.as_slice(), [Line::Label("loop"), Line::Instruction("dec rcx"), _]));
+/// ```
+pub fn parse_statements(line: &str) -> Vec<Line<'_>> {
+    split_statements(line).into_iter().map(parse_line).collect()
+}
+
+/// Parse a line of assembly without comments
+pub fn parse_line(line: &str) -> Line<'_> {
+    let line = line.trim();
+
+    if line.is_empty() {
+        return Line::None;
+    }
+    if is_label(line) {
+        return Line::Label(&line[..line.len() - 1]);
+    }
+    if is_asm_command(line) {
+        return Line::AsmCommand(line);
+    }
+    if is_instruction(line) {
+        return Line::Instruction(line);
+    }
+    Line::Unknown(line)
 }
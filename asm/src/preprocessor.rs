@@ -0,0 +1,1132 @@
+use crate::{
+    error::{AsmError, AsmWarning, Diagnostic, Span},
+    expression,
+    functions::{
+        adjacent_separator, is_keyword, is_keyword_char, is_quote, parse_string_literal,
+        split_arguments, split_statements, string_end, strip_comments,
+    },
+    line::Line,
+    parser::parse_line,
+    syntax::Syntax,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Error, Formatter},
+    fs,
+    io::Read,
+    path::{Component, Path, PathBuf},
+    rc::Rc,
+};
+
+/// Maximum depth of nested macro expansions
+const MAX_EXPANSION_DEPTH: usize = 64;
+
+/// Maximum count of .rept
+const MAX_REPEAT_COUNT: usize = 0x10000;
+
+/// Commands which start blocks closed by .endr
+const REPEAT_COMMANDS: [&str; 3] = [".rept", ".irp", ".irpc"];
+
+/// Maximum depth of nested includes
+const MAX_INCLUDE_DEPTH: usize = 64;
+
+/// Maximum size of files read by .include and .incbin
+const MAX_FILE_SIZE: u64 = 1 << 24;
+
+/// Maximum total size of lines made by macros and repetitions
+const MAX_EXPANDED_SIZE: usize = 1 << 26;
+
+/// Maximum length which substitution of defines and macro arguments can add to a line
+const MAX_SUBSTITUTION_GROWTH: usize = 1 << 16;
+
+/// Location of a line in source
+/// Lines expanded from macros know where the macro is called,
+/// and lines in included files know where the file is included
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    file: Option<PathBuf>,
+    line: usize,
+    expansion: Option<Box<Location>>,
+    inclusion: Option<Box<Location>>,
+    offset: usize,
+    source: Rc<str>,
+}
+
+impl Location {
+    /// Construct Location of line in source
+    pub fn new(line: usize) -> Self {
+        Location {
+            file: None,
+            line,
+            expansion: None,
+            inclusion: None,
+            offset: 0,
+            source: Rc::from(""),
+        }
+    }
+
+    /// Construct Location of line in included file
+    pub fn in_file(file: &Path, line: usize) -> Self {
+        Location {
+            file: Some(file.to_path_buf()),
+            line,
+            expansion: None,
+            inclusion: None,
+            offset: 0,
+            source: Rc::from(""),
+        }
+    }
+
+    /// Set text of the line in source and byte offset of its start in file
+    pub fn with_source(self, offset: usize, source: &str) -> Self {
+        Location {
+            offset,
+            source: Rc::from(source),
+            ..self
+        }
+    }
+
+    /// Get included file which contains this line
+    /// This is None for lines in source given to Asm
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Get line number
+    /// This is the line in macro body for expanded lines
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get location where the macro is called
+    pub fn expansion(&self) -> Option<&Location> {
+        self.expansion.as_deref()
+    }
+
+    /// Get location of .include which includes the file of this line
+    pub fn inclusion(&self) -> Option<&Location> {
+        self.inclusion.as_deref()
+    }
+
+    /// Get position in the whole source as lines and offsets from the outermost source
+    /// Lines in macros and included files are placed where they are expanded or included
+    pub fn source_order(&self) -> Vec<(usize, usize)> {
+        let mut order = match self.expansion().or(self.inclusion()) {
+            Some(outer) => outer.source_order(),
+            None => Vec::new(),
+        };
+        order.push((self.line, self.offset));
+        order
+    }
+
+    /// Get byte offset of the start of line in file
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get text of the line in source
+    /// This is the line in macro body for expanded lines
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn expanded_at(&self, call: &Location) -> Self {
+        Location {
+            expansion: Some(Box::new(call.clone())),
+            ..self.clone()
+        }
+    }
+
+    fn included_at(self, include: &Location) -> Self {
+        Location {
+            inclusion: Some(Box::new(include.clone())),
+            ..self
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match (&self.expansion, &self.file) {
+            (Some(call), _) => write!(f, "{}, macro body line {}", call, self.line),
+            (None, Some(file)) => write!(f, "{} line {}", file.display(), self.line),
+            (None, None) => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+/// Line of preprocessed source
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceLine {
+    text: String,
+    location: Location,
+}
+
+impl SourceLine {
+    /// Construct SourceLine
+    pub fn new(text: &str, location: Location) -> Self {
+        SourceLine {
+            text: text.to_string(),
+            location,
+        }
+    }
+
+    /// Get text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get location
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+}
+
+/// Macro definition
+#[derive(Clone, Debug)]
+struct Macro {
+    parameters: Vec<(String, Option<String>)>,
+    body: Vec<SourceLine>,
+}
+
+impl Macro {
+    /// Bind arguments to parameters
+    /// Arguments are positional or `name=value`
+    fn bind(&self, arguments: &[&str]) -> Result<Vec<String>, String> {
+        let mut values: Vec<Option<String>> = vec![None; self.parameters.len()];
+
+        for (i, argument) in arguments.iter().enumerate() {
+            let keyword = argument.split_once('=').and_then(|(name, value)| {
+                let index = self.parameters.iter().position(|p| p.0 == name.trim())?;
+                Some((index, value.trim()))
+            });
+            let (index, value) = match keyword {
+                Some(k) => k,
+                None if i < self.parameters.len() => (i, *argument),
+                None => return Err("too many macro arguments".to_string()),
+            };
+            if !value.is_empty() {
+                values[index] = Some(value.to_string());
+            }
+        }
+
+        Ok(values
+            .into_iter()
+            .zip(&self.parameters)
+            .map(|(value, (_, default))| value.or(default.clone()).unwrap_or_default())
+            .collect())
+    }
+
+    /// Replace `\parameter` and `\@` in line
+    fn substitute(&self, line: &str, values: &[String], counter: usize) -> Result<String, String> {
+        let mut substituted = String::with_capacity(line.len());
+        let mut rest = line;
+
+        while let Some(index) = rest.find('\\') {
+            if line.len() + MAX_SUBSTITUTION_GROWTH < substituted.len() {
+                return Err("macro expansion too long".to_string());
+            }
+            substituted += &rest[..index];
+            rest = &rest[index + 1..];
+
+            if let Some(r) = rest.strip_prefix('@') {
+                substituted += &counter.to_string();
+                rest = r;
+            } else if let Some(r) = rest.strip_prefix("()") {
+                rest = r;
+            } else {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(rest.len());
+                match self.parameters.iter().position(|p| p.0 == rest[..len]) {
+                    Some(i) if len != 0 => {
+                        substituted += &values[i];
+                        rest = &rest[len..];
+                    }
+                    _ => substituted.push('\\'),
+                }
+            }
+        }
+        substituted += rest;
+
+        if line.len() + MAX_SUBSTITUTION_GROWTH < substituted.len() {
+            return Err("macro expansion too long".to_string());
+        }
+        Ok(substituted)
+    }
+}
+
+/// State of conditional block
+#[derive(Clone, Debug)]
+struct Conditional {
+    location: Location,
+    parent_active: bool,
+    taken: bool,
+    active: bool,
+    has_else: bool,
+}
+
+/// Preprocessor which expands macros, conditional blocks and includes before parsing
+/// # Example
+/// ```
+/// use asm::preprocessor::Preprocessor;
+/// let source = "
+///     .macro incby reg, value=1
+///     add \\reg \\value
+///     .endm
+///     incby rax
+///     incby rcx, 2";
+///
+/// let lines = Preprocessor::new().process(source).unwrap();
+/// let texts: Vec<&str> = lines.iter().map(|l| l.text().trim()).collect();
+///
+/// assert_eq!(vec!["", "add rax 1", "add rcx 2"], texts);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Preprocessor {
+    macros: HashMap<String, Macro>,
+    counter: usize,
+    conditionals: Vec<Conditional>,
+    constants: HashMap<String, Option<i128>>,
+    labels: HashSet<String>,
+    include_paths: Vec<PathBuf>,
+    absolute_includes: bool,
+    includes: Vec<PathBuf>,
+    defines: HashMap<String, String>,
+    syntax: Syntax,
+    label_scope: String,
+    diagnostics: Vec<Diagnostic>,
+    expanded_size: usize,
+}
+
+impl Preprocessor {
+    /// Construct Preprocessor
+    pub fn new() -> Self {
+        Preprocessor {
+            macros: HashMap::new(),
+            counter: 0,
+            conditionals: Vec::new(),
+            constants: HashMap::new(),
+            labels: HashSet::new(),
+            include_paths: Vec::new(),
+            absolute_includes: false,
+            includes: Vec::new(),
+            defines: HashMap::new(),
+            syntax: Syntax::Intel,
+            label_scope: String::new(),
+            diagnostics: Vec::new(),
+            expanded_size: 0,
+        }
+    }
+
+    /// Set syntax of source
+    /// Lines are translated by Syntax::translate_line before preprocessing
+    pub fn set_syntax(&mut self, syntax: Syntax) {
+        self.syntax = syntax;
+    }
+
+    /// Add directory which .include and .incbin search files in
+    /// Directories are searched in the order they are added, after directory of the including file
+    pub fn add_include_path(&mut self, path: &Path) {
+        self.include_paths.push(path.to_path_buf());
+    }
+
+    /// Allow .include and .incbin to read files by absolute paths
+    /// Relative paths are always kept inside directory of the including file and include paths
+    pub fn set_absolute_includes(&mut self, absolute_includes: bool) {
+        self.absolute_includes = absolute_includes;
+    }
+
+    /// Preprocess source
+    /// Returns the first error
+    pub fn process(&mut self, source: &str) -> Result<Vec<SourceLine>, AsmError> {
+        let (lines, diagnostics) = self.process_all(source);
+        match diagnostics.into_iter().find_map(Diagnostic::into_error) {
+            Some(error) => Err(error),
+            None => Ok(lines),
+        }
+    }
+
+    /// Preprocess source and collect all errors and warnings
+    /// Lines with errors are left out, and the other lines are still processed
+    pub fn process_all(&mut self, source: &str) -> (Vec<SourceLine>, Vec<Diagnostic>) {
+        let lines = self.source_lines(source, Location::new);
+
+        let mut output = Vec::new();
+        self.process_lines(&lines, &mut output, 0);
+        for conditional in self.conditionals.drain(..).rev() {
+            self.diagnostics
+                .push(AsmError::preprocessor(&conditional.location, ".if without .endif").into());
+        }
+        for error in resolve_local_labels(&mut output) {
+            self.diagnostics.push(error.into());
+        }
+        (output, std::mem::take(&mut self.diagnostics))
+    }
+
+    fn process_lines(&mut self, lines: &[SourceLine], output: &mut Vec<SourceLine>, depth: usize) {
+        let mut index = 0;
+
+        while index < lines.len() {
+            index = match self.process_line(lines, index, output, depth) {
+                Ok(next) => next,
+                Err(error) => {
+                    self.diagnostics.push(error.into());
+                    index + 1
+                }
+            };
+        }
+    }
+
+    /// Process line at index
+    /// Returns index of the next line
+    fn process_line(
+        &mut self,
+        lines: &[SourceLine],
+        index: usize,
+        output: &mut Vec<SourceLine>,
+        depth: usize,
+    ) -> Result<usize, AsmError> {
+        let error = |message: &str| AsmError::preprocessor(lines[index].location(), message);
+        let text = self
+            .substitute_defines(lines[index].text())
+            .map_err(|e| error(&e))?;
+        let line = &SourceLine::new(&text, lines[index].location().clone());
+        let (name, arguments) = split_statement(line.text());
+
+        if self
+            .conditional(name, arguments, line.location())
+            .map_err(|e| error(&e))?
+            || !self.is_active()
+        {
+            return Ok(index + 1);
+        }
+
+        match name {
+            ".macro" => {
+                // Unclosed block swallows the rest of lines
+                let Some(end) = find_block_end(lines, index, &[".macro"], ".endm") else {
+                    self.diagnostics.push(error(".macro without .endm").into());
+                    return Ok(lines.len());
+                };
+                let redefined = self
+                    .define_macro(arguments, &lines[index + 1..end])
+                    .map_err(|e| error(&e))?;
+                if let Some(name) = redefined {
+                    self.diagnostics.push(
+                        AsmWarning::MacroRedefined {
+                            name: name.to_string(),
+                            span: Span::new(line.location(), name),
+                        }
+                        .into(),
+                    );
+                }
+                return Ok(end + 1);
+            }
+            ".endm" => return Err(error(".endm without .macro")),
+            _ if REPEAT_COMMANDS.contains(&name) => {
+                let Some(end) = find_block_end(lines, index, &REPEAT_COMMANDS, ".endr") else {
+                    self.diagnostics
+                        .push(error("repetition without .endr").into());
+                    return Ok(lines.len());
+                };
+                if MAX_EXPANSION_DEPTH <= depth {
+                    self.stop_expansion(error("macro expansion too deep"));
+                    return Ok(end + 1);
+                }
+                let expanded = self
+                    .expand_repetition(name, arguments, &lines[index + 1..end])
+                    .map_err(|e| error(&e));
+                match expanded {
+                    Ok(expanded) => self.process_lines(&expanded, output, depth + 1),
+                    Err(error) => self.diagnostics.push(error.into()),
+                }
+                return Ok(end + 1);
+            }
+            ".endr" => return Err(error(".endr without repetition")),
+            ".include" => {
+                let (path, source) = self.read_include(arguments).map_err(|e| error(&e))?;
+                let included = self.source_lines(&source, |l| {
+                    Location::in_file(&path, l).included_at(line.location())
+                });
+                self.includes.push(path);
+                self.process_lines(&included, output, depth);
+                self.includes.pop();
+            }
+            ".incbin" => {
+                let text = self.read_incbin(arguments).map_err(|e| error(&e))?;
+                output.push(SourceLine::new(&text, line.location().clone()));
+            }
+            ".define" => {
+                let (define, value) = split_statement(arguments);
+                if !is_keyword(define) {
+                    return Err(error("invalid define name"));
+                }
+                self.defines.insert(define.to_string(), value.to_string());
+            }
+            ".undef" => {
+                self.defines.remove(arguments.trim());
+            }
+            ".purgem" => {
+                if self.macros.remove(arguments.trim()).is_none() {
+                    return Err(error("unknown macro"));
+                }
+            }
+            _ if self.macros.contains_key(name) => {
+                if MAX_EXPANSION_DEPTH <= depth {
+                    self.stop_expansion(error("macro expansion too deep"));
+                    return Ok(index + 1);
+                }
+                let expanded = self
+                    .expand_macro(name, arguments, line.location())
+                    .map_err(|e| error(&e))?;
+                self.process_lines(&expanded, output, depth + 1);
+            }
+            _ => {
+                self.record_symbols(line.text());
+                output.push(line.clone());
+            }
+        }
+        Ok(index + 1)
+    }
+
+    /// If lines are assembled at this point
+    fn is_active(&self) -> bool {
+        self.conditionals.last().is_none_or(|c| c.active)
+    }
+
+    /// Process conditional command
+    /// Returns false if this isn't a conditional command
+    fn conditional(
+        &mut self,
+        name: &str,
+        arguments: &str,
+        location: &Location,
+    ) -> Result<bool, String> {
+        match name {
+            ".if" | ".ifdef" | ".ifndef" => {
+                let parent_active = self.is_active();
+                let condition = if parent_active {
+                    match name {
+                        ".if" => self.evaluate_condition(arguments),
+                        ".ifdef" => self.is_defined(arguments),
+                        _ => self.is_defined(arguments).map(|defined| !defined),
+                    }
+                } else {
+                    Ok(false)
+                };
+                // Block with invalid condition is skipped with all of its branches
+                self.conditionals.push(Conditional {
+                    location: location.clone(),
+                    parent_active,
+                    taken: *condition.as_ref().unwrap_or(&true),
+                    active: *condition.as_ref().unwrap_or(&false),
+                    has_else: false,
+                });
+                condition?;
+            }
+            ".elseif" => {
+                let Some(conditional) = self.conditionals.last() else {
+                    return Err(".elseif without .if".to_string());
+                };
+                if conditional.has_else {
+                    return Err(".elseif after .else".to_string());
+                }
+                let condition = if conditional.parent_active && !conditional.taken {
+                    self.evaluate_condition(arguments)
+                } else {
+                    Ok(false)
+                };
+                let Some(conditional) = self.conditionals.last_mut() else {
+                    return Err(".elseif without .if".to_string());
+                };
+                conditional.active = *condition.as_ref().unwrap_or(&false);
+                conditional.taken |= *condition.as_ref().unwrap_or(&true);
+                condition?;
+            }
+            ".else" => {
+                let Some(conditional) = self.conditionals.last_mut() else {
+                    return Err(".else without .if".to_string());
+                };
+                if conditional.has_else {
+                    return Err(".else after .else".to_string());
+                }
+                conditional.active = conditional.parent_active && !conditional.taken;
+                conditional.taken = true;
+                conditional.has_else = true;
+            }
+            ".endif" => {
+                if self.conditionals.pop().is_none() {
+                    return Err(".endif without .if".to_string());
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Remember constants and labels to evaluate conditions
+    fn record_symbols(&mut self, text: &str) {
+        let line = parse_line(text);
+        match line {
+            Line::Label(name) => {
+                self.labels.insert(name.to_string());
+            }
+            Line::AsmCommand(_) => {
+                if let Some((name, expr, _)) = line.constant_definition() {
+                    // Constants depending on labels or $ are defined but have no value yet
+                    let value = self.evaluate_value(expr).ok();
+                    self.constants.insert(name.to_string(), value);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn is_defined(&self, name: &str) -> Result<bool, String> {
+        let name = name.trim();
+        if !is_keyword(name) {
+            return Err("invalid symbol : ".to_string() + name);
+        }
+        Ok(self.constants.contains_key(name)
+            || self.labels.contains(name)
+            || self.defines.contains_key(name))
+    }
+
+    /// Replace names defined by .define with their values
+    /// Names in .define, .undef, .ifdef and .ifndef aren't replaced
+    /// Substituted lines are translated again since values may be in syntax of source
+    fn substitute_defines(&self, text: &str) -> Result<String, String> {
+        let (name, _) = split_statement(text);
+        if self.defines.is_empty() || [".define", ".undef", ".ifdef", ".ifndef"].contains(&name) {
+            return Ok(text.to_string());
+        }
+
+        let mut substituted = text.to_string();
+        for _ in 0..MAX_EXPANSION_DEPTH {
+            let mut replaced = String::with_capacity(substituted.len());
+            let mut rest = substituted.as_str();
+            let mut previous = ' ';
+            while let Some(c) = rest.chars().next() {
+                if is_quote(c) {
+                    let end = string_end(rest, 0).unwrap_or(rest.len());
+                    replaced += &rest[..end];
+                    previous = c;
+                    rest = &rest[end..];
+                    continue;
+                }
+                let len = rest
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(rest.len())
+                    .max(c.len_utf8());
+                let word = &rest[..len];
+                if text.len() + MAX_SUBSTITUTION_GROWTH < replaced.len() {
+                    return Err("define expansion too long".to_string());
+                }
+                match self.defines.get(word) {
+                    Some(value) if previous != '\\' && previous != '.' => replaced += value,
+                    _ => replaced += word,
+                }
+                previous = word.chars().last().unwrap_or(' ');
+                rest = &rest[len..];
+            }
+            if replaced == substituted {
+                if substituted == text {
+                    return Ok(substituted);
+                }
+                return self.syntax.translate_substituted(&substituted);
+            }
+            substituted = replaced;
+        }
+        Err("define expansion too deep".to_string())
+    }
+
+    fn evaluate_value(&self, expr: &str) -> Result<i128, String> {
+        expression::evaluate(expr, |name| self.constants.get(name).copied().flatten())
+    }
+
+    /// Evaluate condition of .if and .elseif
+    fn evaluate_condition(&self, expr: &str) -> Result<bool, String> {
+        Ok(self.evaluate_value(expr)? != 0)
+    }
+
+    /// Find file in directory of current file and include paths
+    /// `..` is allowed only if the path doesn't go out of them,
+    /// and absolute paths are allowed only by Preprocessor::set_absolute_includes
+    fn resolve_path(&self, arguments: &str) -> Result<PathBuf, String> {
+        let name = parse_string_literal(arguments)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or("invalid file name")?;
+        let path = Path::new(&name);
+        if path.is_absolute() {
+            if !self.absolute_includes {
+                return Err("absolute file name isn't allowed : ".to_string() + &name);
+            }
+            if !path.is_file() {
+                return Err("file not found : ".to_string() + &name);
+            }
+            return Ok(path.to_path_buf());
+        }
+        let path = normalize_path(path)
+            .ok_or_else(|| "file name must be inside include paths : ".to_string() + &name)?;
+
+        let current_dir = self.includes.last().and_then(|file| file.parent());
+        current_dir
+            .into_iter()
+            .chain(self.include_paths.iter().map(PathBuf::as_path))
+            .map(|dir| dir.join(&path))
+            .find(|p| p.is_file())
+            .ok_or_else(|| "file not found : ".to_string() + &name)
+    }
+
+    /// Read included file
+    /// Returns path and source of the file
+    fn read_include(&self, arguments: &str) -> Result<(PathBuf, String), String> {
+        let path = self.resolve_path(arguments)?;
+        let canonical = path.canonicalize().map_err(|e| e.to_string())?;
+        if self
+            .includes
+            .iter()
+            .any(|p| p.canonicalize().ok() == Some(canonical.clone()))
+        {
+            let cycle: Vec<String> = self
+                .includes
+                .iter()
+                .chain([&path])
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err("include cycle : ".to_string() + &cycle.join(" -> "));
+        }
+        if MAX_INCLUDE_DEPTH <= self.includes.len() {
+            return Err("include too deep".to_string());
+        }
+        let source = String::from_utf8(read_file(&path)?).map_err(|_| {
+            "stream did not contain valid UTF-8 : ".to_string() + &path.display().to_string()
+        })?;
+        Ok((path, source))
+    }
+
+    /// Split source into statements without comments, and translate them from syntax of source
+    /// Statements in the same line have the same location
+    /// Statements which can't be translated are reported and left out
+    fn source_lines(
+        &mut self,
+        source: &str,
+        location: impl Fn(usize) -> Location,
+    ) -> Vec<SourceLine> {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        let raw_lines = source.split_inclusive('\n');
+        for ((i, text), raw) in strip_comments(source, self.syntax)
+            .lines()
+            .enumerate()
+            .zip(raw_lines)
+        {
+            let raw_text = raw.strip_suffix('\n').unwrap_or(raw);
+            let raw_text = raw_text.strip_suffix('\r').unwrap_or(raw_text);
+            let location = location(i + 1).with_source(offset, raw_text);
+            offset += raw.len();
+            let mut statements = split_statements(text);
+            if statements.is_empty() {
+                statements.push(text);
+            }
+            for statement in statements {
+                if adjacent_separator(statement).is_some() {
+                    let message =
+                        "@ next to a name doesn't separate statements : ".to_string() + statement;
+                    let error = AsmError::preprocessor(&location, &message);
+                    self.diagnostics.push(error.into());
+                    continue;
+                }
+                let translated = match self.syntax.translate_line(statement, &mut self.label_scope)
+                {
+                    Ok(translated) => translated,
+                    Err(e) => {
+                        let error = AsmError::preprocessor(&location, &e);
+                        self.diagnostics.push(error.into());
+                        continue;
+                    }
+                };
+                lines.extend(
+                    translated
+                        .iter()
+                        .map(|t| SourceLine::new(t, location.clone())),
+                );
+            }
+        }
+        lines
+    }
+
+    /// Read included binary file and convert it into .byte command
+    /// Arguments are `"file", offset, len`
+    fn read_incbin(&self, arguments: &str) -> Result<String, String> {
+        let arguments = split_arguments(arguments);
+        if arguments.is_empty() || 3 < arguments.len() {
+            return Err("invalid arguments".to_string());
+        }
+        let path = self.resolve_path(arguments[0])?;
+        let bytes = read_file(&path)?;
+
+        let offset = match arguments.get(1) {
+            Some(expr) => usize::try_from(self.evaluate_value(expr)?).map_err(|e| e.to_string())?,
+            None => 0,
+        };
+        let len = match arguments.get(2) {
+            Some(expr) => usize::try_from(self.evaluate_value(expr)?).map_err(|e| e.to_string())?,
+            None => bytes.len().saturating_sub(offset),
+        };
+        let bytes = offset
+            .checked_add(len)
+            .and_then(|end| bytes.get(offset..end))
+            .ok_or("out of range of file")?;
+
+        if bytes.is_empty() {
+            return Ok(String::new());
+        }
+        let values: Vec<String> = bytes.iter().map(|b| b.to_string()).collect();
+        Ok(".byte ".to_string() + &values.join(", "))
+    }
+
+    /// Expand body of .rept, .irp or .irpc
+    /// Expanded lines keep their location in source
+    fn expand_repetition(
+        &mut self,
+        name: &str,
+        arguments: &str,
+        body: &[SourceLine],
+    ) -> Result<Vec<SourceLine>, String> {
+        // Error is already reported
+        if self.is_expansion_exhausted() {
+            return Ok(Vec::new());
+        }
+        if name == ".rept" {
+            let count = usize::try_from(self.evaluate_value(arguments)?)
+                .ok()
+                .filter(|count| *count <= MAX_REPEAT_COUNT)
+                .ok_or("invalid repeat count")?;
+            self.reserve_expansion(expanded_size(body).saturating_mul(count))?;
+            return Ok(body
+                .iter()
+                .cycle()
+                .take(body.len() * count)
+                .cloned()
+                .collect());
+        }
+
+        let (parameter, values) = match arguments.split_once(',') {
+            Some((parameter, values)) => (parameter.trim(), values.trim()),
+            None => (arguments.trim(), ""),
+        };
+        if !is_keyword(parameter) {
+            return Err("invalid parameter : ".to_string() + parameter);
+        }
+        let values: Vec<String> = if name == ".irp" {
+            split_arguments(values)
+                .into_iter()
+                .map(|v| v.to_string())
+                .collect()
+        } else {
+            values.chars().map(|c| c.to_string()).collect()
+        };
+
+        let repetition = Macro {
+            parameters: vec![(parameter.to_string(), None)],
+            body: body.to_vec(),
+        };
+        let mut expanded = Vec::new();
+        for value in values {
+            let counter = self.counter;
+            self.counter += 1;
+            for line in &repetition.body {
+                expanded.push(SourceLine {
+                    text: repetition.substitute(
+                        line.text(),
+                        std::slice::from_ref(&value),
+                        counter,
+                    )?,
+                    location: line.location().clone(),
+                });
+            }
+            self.reserve_expansion(expanded_size(&expanded[expanded.len() - body.len()..]))?;
+        }
+        Ok(expanded)
+    }
+
+    /// Define macro
+    /// Returns the name if macro with the same name is replaced
+    fn define_macro<'b>(
+        &mut self,
+        arguments: &'b str,
+        body: &[SourceLine],
+    ) -> Result<Option<&'b str>, String> {
+        let (name, parameters) = match arguments.trim().split_once(|c: char| c.is_whitespace()) {
+            Some((name, parameters)) => (name, parameters.trim()),
+            None => (arguments.trim(), ""),
+        };
+        let parameters = parameters.strip_prefix(',').unwrap_or(parameters);
+        if !is_keyword(name) {
+            return Err("invalid macro name".to_string());
+        }
+
+        let mut parsed_parameters: Vec<(String, Option<String>)> = Vec::new();
+        let parameters = parameters
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|p| !p.is_empty());
+        for parameter in parameters {
+            let (parameter, default) = match parameter.split_once('=') {
+                Some((p, d)) => (p.trim(), Some(d.trim().to_string())),
+                None => (parameter, None),
+            };
+            if !is_keyword(parameter) || parsed_parameters.iter().any(|p| p.0 == parameter) {
+                return Err("invalid macro parameter : ".to_string() + parameter);
+            }
+            parsed_parameters.push((parameter.to_string(), default));
+        }
+
+        let replaced = self.macros.insert(
+            name.to_string(),
+            Macro {
+                parameters: parsed_parameters,
+                body: body.to_vec(),
+            },
+        );
+        Ok(replaced.map(|_| name))
+    }
+
+    fn expand_macro(
+        &mut self,
+        name: &str,
+        arguments: &str,
+        call: &Location,
+    ) -> Result<Vec<SourceLine>, String> {
+        // Error is already reported
+        if self.is_expansion_exhausted() {
+            return Ok(Vec::new());
+        }
+        let r#macro = &self.macros[name];
+        let values = r#macro.bind(&split_arguments(arguments))?;
+        let counter = self.counter;
+        self.counter += 1;
+
+        let expanded = r#macro
+            .body
+            .iter()
+            .map(|line| {
+                Ok(SourceLine {
+                    text: r#macro.substitute(line.text(), &values, counter)?,
+                    location: line.location().expanded_at(call),
+                })
+            })
+            .collect::<Result<Vec<SourceLine>, String>>()?;
+        self.reserve_expansion(expanded_size(&expanded))?;
+        Ok(expanded)
+    }
+
+    /// Count size of lines made by expansion
+    /// Fails if the total size exceeds MAX_EXPANDED_SIZE, and the following expansions make no lines
+    fn reserve_expansion(&mut self, size: usize) -> Result<(), String> {
+        self.expanded_size = self.expanded_size.saturating_add(size);
+        if self.is_expansion_exhausted() {
+            self.expanded_size = usize::MAX;
+            return Err("too many lines are expanded".to_string());
+        }
+        Ok(())
+    }
+
+    /// Report error and stop the following expansions
+    /// Too deep expansion is usually recursion, which would report every level of it
+    fn stop_expansion(&mut self, error: AsmError) {
+        if !self.is_expansion_exhausted() {
+            self.expanded_size = usize::MAX;
+            self.diagnostics.push(error.into());
+        }
+    }
+
+    /// If the total size of expanded lines exceeded MAX_EXPANDED_SIZE
+    fn is_expansion_exhausted(&self) -> bool {
+        MAX_EXPANDED_SIZE < self.expanded_size
+    }
+}
+
+/// Size of lines counted for MAX_EXPANDED_SIZE
+fn expanded_size(lines: &[SourceLine]) -> usize {
+    lines
+        .iter()
+        .map(|line| size_of::<SourceLine>() + line.text().len())
+        .sum()
+}
+
+/// Read file up to MAX_FILE_SIZE
+fn read_file(path: &Path) -> Result<Vec<u8>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("{} : {}", e, path.display()))?;
+    let mut bytes = Vec::new();
+    file.take(MAX_FILE_SIZE + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("{} : {}", e, path.display()))?;
+    if MAX_FILE_SIZE < bytes.len() as u64 {
+        return Err("file too large : ".to_string() + &path.display().to_string());
+    }
+    Ok(bytes)
+}
+
+/// Remove `.` and `..` from relative path without following links
+/// Returns None if the path goes out of the directory it starts from
+fn normalize_path(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => normalized.push(name),
+            Component::CurDir => (),
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(normalized)
+}
+
+/// Rename local labels into unique names
+/// `.L` labels are scoped to the last global label, and numeric labels like `1:` are referred as `1b` or `1f`
+/// Lines with undefined references are left as they are, and returned errors report them
+fn resolve_local_labels(lines: &mut [SourceLine]) -> Vec<AsmError> {
+    let mut errors = Vec::new();
+    let mut defined: HashMap<String, usize> = HashMap::new();
+    for line in lines.iter() {
+        if let Some(label) = numeric_label(line.text()) {
+            *defined.entry(label.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut scope = String::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for line in lines.iter_mut() {
+        let text = if let Some(label) = numeric_label(&line.text) {
+            let count = counts.entry(label.to_string()).or_default();
+            *count += 1;
+            numeric_label_name(label, *count - 1) + ":"
+        } else {
+            if let Line::Label(name) = parse_line(&line.text) {
+                if !name.starts_with(".L") {
+                    scope = name.to_string();
+                }
+            }
+            match rename_local_references(&line.text, &scope, &counts, &defined) {
+                Ok(text) => text,
+                Err(label) => {
+                    errors.push(AsmError::UndefinedLabel {
+                        span: Span::new(&line.location, &label),
+                        label,
+                        suggestion: None,
+                    });
+                    // Leave the line out not to report it again
+                    String::new()
+                }
+            }
+        };
+        line.text = text;
+    }
+
+    errors
+}
+
+/// Replace `.L` labels and references to numeric labels in line
+/// Returns undefined reference as error
+fn rename_local_references(
+    text: &str,
+    scope: &str,
+    counts: &HashMap<String, usize>,
+    defined: &HashMap<String, usize>,
+) -> Result<String, String> {
+    let mut renamed = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let len = if is_quote(c) {
+            string_end(rest, 0).unwrap_or(rest.len())
+        } else if is_keyword_char(c) && c != '$' {
+            rest.find(|c| !is_keyword_char(c)).unwrap_or(rest.len())
+        } else {
+            c.len_utf8()
+        };
+        let word = &rest[..len];
+        rest = &rest[len..];
+
+        if word.starts_with(".L") && is_keyword(word) {
+            renamed += scope;
+            renamed += word;
+        } else if let Some((label, backward)) = numeric_reference(word) {
+            let count = counts.get(label).copied().unwrap_or(0);
+            let index = if backward {
+                count.checked_sub(1)
+            } else {
+                Some(count).filter(|c| *c < defined.get(label).copied().unwrap_or(0))
+            };
+            let index = index.ok_or_else(|| word.to_string())?;
+            renamed += &numeric_label_name(label, index);
+        } else {
+            renamed += word;
+        }
+    }
+
+    Ok(renamed)
+}
+
+/// Get name of numeric label if this line defines it
+fn numeric_label(text: &str) -> Option<&str> {
+    let label = text.trim().strip_suffix(':')?.trim();
+    if !label.is_empty() && label.chars().all(|c| c.is_ascii_digit()) {
+        Some(label)
+    } else {
+        None
+    }
+}
+
+/// Parse reference to numeric label like `1b`
+/// Returns label and if it refers backward
+fn numeric_reference(word: &str) -> Option<(&str, bool)> {
+    let (label, backward) = match word.strip_suffix('b') {
+        Some(label) => (label, true),
+        None => (word.strip_suffix('f')?, false),
+    };
+    if !label.is_empty() && label.chars().all(|c| c.is_ascii_digit()) {
+        Some((label, backward))
+    } else {
+        None
+    }
+}
+
+/// Unique name of the index-th definition of numeric label
+fn numeric_label_name(label: &str, index: usize) -> String {
+    format!(".L{}${}", label, index)
+}
+
+/// Split line into first word and the rest
+pub fn split_statement(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(|c: char| c.is_whitespace()) {
+        Some((name, rest)) => (name, rest.trim()),
+        None => (line, ""),
+    }
+}
+
+/// Find index of line which closes block started at index
+fn find_block_end(lines: &[SourceLine], index: usize, starts: &[&str], end: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, line) in lines.iter().enumerate().skip(index) {
+        let (name, _) = split_statement(line.text());
+        if starts.contains(&name) {
+            depth += 1;
+        } else if name == end {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+
+    None
+}
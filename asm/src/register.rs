@@ -89,11 +89,7 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if RAX_USIZE <= self_usize && self_usize <= RIP_USIZE {
-            true
-        } else {
-            false
-        }
+        (RAX_USIZE..=RIP_USIZE).contains(&self_usize)
     }
 
     /// If this register is 32bit
@@ -103,11 +99,7 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if EAX_USIZE <= self_usize && self_usize <= R15D_USIZE {
-            true
-        } else {
-            false
-        }
+        (EAX_USIZE..=R15D_USIZE).contains(&self_usize)
     }
 
     /// If this register is 16bit
@@ -117,11 +109,7 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if AX_USIZE <= self_usize && self_usize <= R15W_USIZE {
-            true
-        } else {
-            false
-        }
+        (AX_USIZE..=R15W_USIZE).contains(&self_usize)
     }
 
     /// If this register is 8bit
@@ -131,11 +119,7 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if AL_USIZE <= self_usize && self_usize <= R15L_USIZE {
-            true
-        } else {
-            false
-        }
+        (AL_USIZE..=R15L_USIZE).contains(&self_usize)
     }
 
     /// Get 8bit register code
@@ -232,12 +216,11 @@ impl Register {
         }
     }
 
-    pub fn to_regcode(self) -> (Option<bool>, u8) {
+    pub fn to_regcode(self) -> Option<(Option<bool>, u8)> {
         self.to_regcode8()
             .or(self.to_regcode16())
             .or(self.to_regcode32())
             .or(self.to_regcode64())
-            .expect("internal error")
     }
 
     pub fn operand_r64(self) -> bool {
@@ -246,11 +229,7 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if RAX_USIZE <= self_usize && self_usize <= RIP_USIZE {
-            true
-        } else {
-            false
-        }
+        (RAX_USIZE..=RIP_USIZE).contains(&self_usize)
     }
 
     pub fn operand_r32(self) -> bool {
@@ -259,11 +238,7 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if EAX_USIZE <= self_usize && self_usize <= R15D_USIZE {
-            true
-        } else {
-            false
-        }
+        (EAX_USIZE..=R15D_USIZE).contains(&self_usize)
     }
 
     pub fn operand_r16(self) -> bool {
@@ -272,11 +247,7 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if AX_USIZE <= self_usize && self_usize <= R15W_USIZE {
-            true
-        } else {
-            false
-        }
+        (AX_USIZE..=R15W_USIZE).contains(&self_usize)
     }
 
     pub fn operand_r8(self) -> bool {
@@ -285,11 +256,13 @@ impl Register {
 
         let self_usize = self as usize;
 
-        if AL_USIZE <= self_usize && self_usize <= R15L_USIZE {
-            true
-        } else {
-            false
-        }
+        (AL_USIZE..=R15L_USIZE).contains(&self_usize)
+    }
+
+    /// If rex prefix is required to encode this register
+    /// spl, bpl, sil and dil mean ah, ch, dh and bh without it
+    pub fn requires_rex_prefix(self) -> bool {
+        matches!(self, Self::Spl | Self::Bpl | Self::Sil | Self::Dil)
     }
 
     pub fn operand_rm_ref_base(self) -> bool {
@@ -297,12 +270,12 @@ impl Register {
     }
 
     pub fn operand_rm_ref_index(self) -> bool {
-        self.operand_r64()
+        self.operand_r64() && self != Self::Rsp
     }
 
     /// Register code for opecode register
-    pub fn register_code_for_opecode_register(self) -> RegisterCode {
-        match self {
+    pub fn register_code_for_opecode_register(self) -> Option<RegisterCode> {
+        Some(match self {
             Self::Al => (Some(false), 0),
             Self::Cl => (Some(false), 1),
             Self::Dl => (Some(false), 2),
@@ -375,98 +348,108 @@ impl Register {
             Self::R14 => (Some(true), 6),
             Self::R15 => (Some(true), 7),
 
-            Self::Rip => todo!("Register::Rip doesn't have register code for addreg"),
-        }
+            // rip can't be encoded as register
+            Self::Rip => return None,
+        })
     }
 
-    pub fn register_code_for_rm_ref_base(self) -> RegisterCode {
-        if self.operand_rm_ref_base() {
+    pub fn register_code_for_rm_ref_base(self) -> Option<RegisterCode> {
+        // rip relative addressing is encoded as mod 00 and r/m 101
+        if self == Self::Rip {
+            Some((Some(false), 0b101))
+        } else if self.operand_rm_ref_base() {
             self.register_code_for_opecode_register()
         } else {
-            panic!("invalid input")
+            None
         }
     }
 
-    pub fn register_code_for_rm_ref_index(self) -> RegisterCode {
+    pub fn register_code_for_rm_ref_index(self) -> Option<RegisterCode> {
         if self == Register::Rip {
-            panic!("invalid input")
+            None
         } else {
             self.register_code_for_rm_ref_base()
         }
     }
 }
 
+/// Names of registers which operands can refer
+pub const REGISTERS: [(&str, Register); 65] = [
+    ("rax", Register::Rax),
+    ("rcx", Register::Rcx),
+    ("rdx", Register::Rdx),
+    ("rbx", Register::Rbx),
+    ("rsp", Register::Rsp),
+    ("rbp", Register::Rbp),
+    ("rsi", Register::Rsi),
+    ("rdi", Register::Rdi),
+    ("r8", Register::R8),
+    ("r9", Register::R9),
+    ("r10", Register::R10),
+    ("r11", Register::R11),
+    ("r12", Register::R12),
+    ("r13", Register::R13),
+    ("r14", Register::R14),
+    ("r15", Register::R15),
+    ("rip", Register::Rip),
+    ("eax", Register::Eax),
+    ("ecx", Register::Ecx),
+    ("edx", Register::Edx),
+    ("ebx", Register::Ebx),
+    ("esp", Register::Esp),
+    ("ebp", Register::Ebp),
+    ("esi", Register::Esi),
+    ("edi", Register::Edi),
+    ("r8d", Register::R8d),
+    ("r9d", Register::R9d),
+    ("r10d", Register::R10d),
+    ("r11d", Register::R11d),
+    ("r12d", Register::R12d),
+    ("r13d", Register::R13d),
+    ("r14d", Register::R14d),
+    ("r15d", Register::R15d),
+    ("ax", Register::Ax),
+    ("cx", Register::Cx),
+    ("dx", Register::Dx),
+    ("bx", Register::Bx),
+    ("sp", Register::Sp),
+    ("bp", Register::Bp),
+    ("si", Register::Si),
+    ("di", Register::Di),
+    ("r8w", Register::R8w),
+    ("r9w", Register::R9w),
+    ("r10w", Register::R10w),
+    ("r11w", Register::R11w),
+    ("r12w", Register::R12w),
+    ("r13w", Register::R13w),
+    ("r14w", Register::R14w),
+    ("r15w", Register::R15w),
+    ("al", Register::Al),
+    ("cl", Register::Cl),
+    ("dl", Register::Dl),
+    ("bl", Register::Bl),
+    ("spl", Register::Spl),
+    ("bpl", Register::Bpl),
+    ("sil", Register::Sil),
+    ("dil", Register::Dil),
+    ("r8l", Register::R8l),
+    ("r9l", Register::R9l),
+    ("r10l", Register::R10l),
+    ("r11l", Register::R11l),
+    ("r12l", Register::R12l),
+    ("r13l", Register::R13l),
+    ("r14l", Register::R14l),
+    ("r15l", Register::R15l),
+];
+
 impl FromStr for Register {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "rax" => Self::Rax,
-            "rcx" => Self::Rcx,
-            "rdx" => Self::Rdx,
-            "rbx" => Self::Rbp,
-            "rsp" => Self::Rsp,
-            "rbp" => Self::Rbp,
-            "rsi" => Self::Rsi,
-            "rdi" => Self::Rdi,
-            "r8" => Self::R8,
-            "r9" => Self::R9,
-            "r10" => Self::R10,
-            "r11" => Self::R11,
-            "r12" => Self::R12,
-            "r13" => Self::R13,
-            "r14" => Self::R14,
-            "r15" => Self::R15,
-            "rip" => Self::Rip,
-            "eax" => Self::Eax,
-            "ecx" => Self::Ecx,
-            "edx" => Self::Edx,
-            "ebx" => Self::Ebx,
-            "esp" => Self::Esp,
-            "ebp" => Self::Ebp,
-            "esi" => Self::Esi,
-            "edi" => Self::Edi,
-            "r8d" => Self::R8d,
-            "r9d" => Self::R9d,
-            "r10d" => Self::R10d,
-            "r11d" => Self::R11d,
-            "r12d" => Self::R12d,
-            "r13d" => Self::R13d,
-            "r14d" => Self::R14d,
-            "r15d" => Self::R15d,
-            "ax" => Self::Ax,
-            "cx" => Self::Cx,
-            "dx" => Self::Dx,
-            "bx" => Self::Bx,
-            "sp" => Self::Sp,
-            "bp" => Self::Bp,
-            "si" => Self::Si,
-            "di" => Self::Di,
-            "r8w" => Self::R8w,
-            "r9w" => Self::R9w,
-            "r10w" => Self::R10w,
-            "r11w" => Self::R11w,
-            "r12w" => Self::R12w,
-            "r13w" => Self::R13w,
-            "r14w" => Self::R14w,
-            "r15w" => Self::R15w,
-            "al" => Self::Al,
-            "cl" => Self::Cl,
-            "dl" => Self::Dl,
-            "bl" => Self::Bl,
-            "spl" => Self::Spl,
-            "bpl" => Self::Bpl,
-            "sil" => Self::Sil,
-            "dil" => Self::Dil,
-            "r8l" => Self::R8l,
-            "r9l" => Self::R9l,
-            "r10l" => Self::R10l,
-            "r11l" => Self::R11l,
-            "r12l" => Self::R12l,
-            "r13l" => Self::R13l,
-            "r14l" => Self::R14l,
-            "r15l" => Self::R15l,
-            _ => return Err(()),
-        })
+        REGISTERS
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|(_, register)| *register)
+            .ok_or(())
     }
 }
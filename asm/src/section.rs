@@ -0,0 +1,350 @@
+/// Result of assembling
+/// # Example
+/// ```
+/// use asm::assembler::Asm;
+/// let source = "
+///     .text
+///     mov rax 1
+///     ret
+///     .data
+///     .qword 1";
+///
+/// let assembly = Asm::new(source).assemble().unwrap();
+/// let text = assembly.section(".text").unwrap();
+/// let data = assembly.section(".data").unwrap();
+///
+/// assert!(text.flags().is_executable());
+/// assert!(data.flags().is_writable());
+/// assert_eq!(8, data.size());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Assembly {
+    sections: Vec<Section>,
+}
+
+impl Assembly {
+    /// Construct Assembly from sections
+    pub fn new(sections: Vec<Section>) -> Self {
+        Assembly { sections }
+    }
+
+    /// Get all sections
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    /// Get section by name
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|s| s.name() == name)
+    }
+
+    /// Get flat image which all sections are placed at their address
+    /// Uninitialized sections are filled with zero
+    pub fn image(&self) -> Vec<u8> {
+        let mut image = Vec::new();
+
+        for section in &self.sections {
+            image.resize(section.address(), 0);
+            image.extend_from_slice(section.bytes());
+            image.resize(section.address() + section.size(), 0);
+        }
+
+        image
+    }
+}
+
+/// Section information
+#[derive(Clone, Debug)]
+pub struct Section {
+    name: String,
+    address: usize,
+    bytes: Vec<u8>,
+    size: usize,
+    align: usize,
+    flags: SectionFlags,
+}
+
+impl Section {
+    /// Construct empty Section
+    pub fn new(name: &str, flags: SectionFlags) -> Self {
+        Section {
+            name: name.to_string(),
+            address: 0,
+            bytes: Vec::new(),
+            size: 0,
+            align: flags.default_align(),
+            flags,
+        }
+    }
+
+    /// Get name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get address in image
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    /// Get raw bytes
+    /// This is empty for uninitialized sections
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Get size
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get alignment
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Get flags
+    pub fn flags(&self) -> SectionFlags {
+        self.flags
+    }
+
+    /// Append bytes
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+        if self.flags.is_uninitialized() {
+            if bytes.iter().any(|b| *b != 0) {
+                return Err(format!(
+                    "non-zero data in uninitialized section {}",
+                    self.name
+                ));
+            }
+        } else {
+            self.bytes.extend_from_slice(bytes);
+        }
+        self.size += bytes.len();
+        Ok(())
+    }
+
+    /// Raise alignment of section
+    pub fn align_to(&mut self, align: usize) {
+        self.align = self.align.max(align);
+    }
+
+    /// Grow size without bytes
+    pub fn grow(&mut self, len: usize) {
+        self.size += len;
+    }
+
+    /// Clear contents but keep layout
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.size = 0;
+    }
+}
+
+/// Section flags
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SectionFlags {
+    writable: bool,
+    executable: bool,
+    uninitialized: bool,
+}
+
+impl SectionFlags {
+    /// Flags of .text
+    pub const TEXT: SectionFlags = SectionFlags {
+        writable: false,
+        executable: true,
+        uninitialized: false,
+    };
+
+    /// Flags of .data
+    pub const DATA: SectionFlags = SectionFlags {
+        writable: true,
+        executable: false,
+        uninitialized: false,
+    };
+
+    /// Flags of .rodata
+    pub const RODATA: SectionFlags = SectionFlags {
+        writable: false,
+        executable: false,
+        uninitialized: false,
+    };
+
+    /// Flags of .bss
+    pub const BSS: SectionFlags = SectionFlags {
+        writable: true,
+        executable: false,
+        uninitialized: true,
+    };
+
+    /// Get flags from section name
+    /// Names like .text.hot have the same flags as .text
+    pub fn from_name(name: &str) -> Option<SectionFlags> {
+        const SECTIONS: [(&str, SectionFlags); 4] = [
+            (".text", SectionFlags::TEXT),
+            (".data", SectionFlags::DATA),
+            (".rodata", SectionFlags::RODATA),
+            (".bss", SectionFlags::BSS),
+        ];
+
+        for (base, flags) in SECTIONS {
+            if let Some(suffix) = name.strip_prefix(base) {
+                if suffix.is_empty() || suffix.starts_with('.') {
+                    return Some(flags);
+                }
+            }
+        }
+        None
+    }
+
+    /// If section is writable
+    pub fn is_writable(self) -> bool {
+        self.writable
+    }
+
+    /// If section is executable
+    pub fn is_executable(self) -> bool {
+        self.executable
+    }
+
+    /// If section has no contents in file
+    pub fn is_uninitialized(self) -> bool {
+        self.uninitialized
+    }
+
+    fn default_align(self) -> usize {
+        if self.executable {
+            16
+        } else {
+            8
+        }
+    }
+}
+
+/// Max size of image
+/// Sizes and alignments of sections are limited so that the image fits in it
+pub const MAX_IMAGE_SIZE: usize = 1 << 28;
+
+/// Section list used while assembling
+#[derive(Clone, Debug)]
+pub struct SectionTable {
+    sections: Vec<Section>,
+    current: usize,
+}
+
+impl SectionTable {
+    /// Construct SectionTable which has only .text
+    pub fn new() -> Self {
+        SectionTable {
+            sections: vec![Section::new(".text", SectionFlags::TEXT)],
+            current: 0,
+        }
+    }
+
+    /// Switch current section
+    /// New section is created if it doesn't exist
+    pub fn switch(&mut self, name: &str) -> Result<(), String> {
+        if let Some(index) = self.sections.iter().position(|s| s.name() == name) {
+            self.current = index;
+        } else {
+            let flags = SectionFlags::from_name(name)
+                .ok_or_else(|| "unknown section : ".to_string() + name)?;
+            self.sections.push(Section::new(name, flags));
+            self.current = self.sections.len() - 1;
+        }
+        Ok(())
+    }
+
+    /// Get index of current section
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Get current section
+    pub fn current(&self) -> &Section {
+        &self.sections[self.current]
+    }
+
+    /// Get mutable current section
+    pub fn current_mut(&mut self) -> &mut Section {
+        &mut self.sections[self.current]
+    }
+
+    /// Grow size of current section without bytes
+    /// Fails without growing if the image can exceed MAX_IMAGE_SIZE
+    pub fn grow(&mut self, len: usize) -> Result<(), String> {
+        if MAX_IMAGE_SIZE < self.image_size_bound().saturating_add(len) {
+            return Err(format!("image exceeds {} bytes", MAX_IMAGE_SIZE));
+        }
+        self.current_mut().grow(len);
+        Ok(())
+    }
+
+    /// Raise alignment of current section
+    /// Fails without raising if the image can exceed MAX_IMAGE_SIZE
+    pub fn align_to(&mut self, align: usize) -> Result<(), String> {
+        let old_align = self.current().align();
+        let bound =
+            (self.image_size_bound() - (old_align - 1)).saturating_add(old_align.max(align) - 1);
+        if MAX_IMAGE_SIZE < bound {
+            return Err(format!("image exceeds {} bytes", MAX_IMAGE_SIZE));
+        }
+        self.current_mut().align_to(align);
+        Ok(())
+    }
+
+    /// Get upper bound of size of image, which is reached if every section needs max padding
+    fn image_size_bound(&self) -> usize {
+        self.sections.iter().fold(0, |bound: usize, section| {
+            bound
+                .saturating_add(section.size())
+                .saturating_add(section.align() - 1)
+        })
+    }
+
+    /// Get address of current position in image
+    pub fn current_address(&self) -> usize {
+        let section = self.current();
+        section.address() + section.size()
+    }
+
+    /// Get section by index
+    pub fn get(&self, index: usize) -> &Section {
+        &self.sections[index]
+    }
+
+    /// Get addresses of sections in order of index
+    pub fn addresses(&self) -> Vec<usize> {
+        self.sections.iter().map(Section::address).collect()
+    }
+
+    /// Place sections in order with their alignment
+    pub fn layout(&mut self) {
+        let mut address: usize = 0;
+        for section in &mut self.sections {
+            address = address.next_multiple_of(section.align);
+            section.address = address;
+            address += section.size;
+        }
+    }
+
+    /// Clear contents of all sections and go back to .text
+    pub fn rewind(&mut self) {
+        for section in &mut self.sections {
+            section.clear();
+        }
+        self.current = 0;
+    }
+
+    /// Convert into Assembly
+    pub fn into_assembly(self) -> Assembly {
+        Assembly::new(self.sections)
+    }
+}
+
+impl Default for SectionTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{
+    expression,
+    functions::{is_keyword, is_keyword_char},
+    register::Register,
+    section::SectionTable,
+};
+use std::collections::HashMap;
+
+/// Kind of symbol
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SymbolKind {
+    /// Defined by .equ
+    Constant,
+    /// Defined by .set, which can be redefined by .set
+    Variable,
+    /// Label in executable section
+    CodeLabel,
+    /// Label in non-executable section
+    DataLabel,
+}
+
+impl SymbolKind {
+    /// If this is a label
+    pub fn is_label(self) -> bool {
+        self == SymbolKind::CodeLabel || self == SymbolKind::DataLabel
+    }
+}
+
+/// Symbol information
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Symbol {
+    kind: SymbolKind,
+    value: i128,
+    section: Option<usize>,
+}
+
+impl Symbol {
+    /// Get kind
+    pub fn kind(&self) -> SymbolKind {
+        self.kind
+    }
+
+    /// Get value
+    /// Value of label is its offset from start of image
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// Get index of section which this label belongs to
+    pub fn section(&self) -> Option<usize> {
+        self.section
+    }
+}
+
+/// Symbol table
+/// # Example
+/// ```
+/// use asm::symbol::{SymbolKind, SymbolTable};
+/// let mut symbols = SymbolTable::new();
+///
+/// symbols.define_constant("SIZE", 16, SymbolKind::Constant).unwrap();
+/// symbols.set_location(8);
+/// assert_eq!(Some(16), symbols.value("SIZE"));
+/// assert!(symbols.define_constant("SIZE", 32, SymbolKind::Constant).is_err());
+/// assert_eq!("mov rax 16", symbols.substitute_constants("mov rax SIZE"));
+/// assert_eq!(Ok(40), symbols.evaluate("SIZE * 2 + $"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SymbolTable {
+    symbols: HashMap<String, Symbol>,
+    location: usize,
+    section_start: usize,
+}
+
+impl SymbolTable {
+    /// Construct empty SymbolTable
+    pub fn new() -> Self {
+        SymbolTable {
+            symbols: HashMap::new(),
+            location: 0,
+            section_start: 0,
+        }
+    }
+
+    /// Get symbol by name
+    pub fn get(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.get(name)
+    }
+
+    /// Get value of symbol
+    pub fn value(&self, name: &str) -> Option<i128> {
+        Some(self.get(name)?.value())
+    }
+
+    /// Get value of constant symbol
+    pub fn constant(&self, name: &str) -> Option<i128> {
+        let symbol = self.get(name)?;
+        if symbol.kind().is_label() {
+            None
+        } else {
+            Some(symbol.value())
+        }
+    }
+
+    /// Iterate all symbols
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Symbol)> {
+        self.symbols
+            .iter()
+            .map(|(name, symbol)| (name.as_str(), symbol))
+    }
+
+    /// Define constant symbol
+    /// Only variables can be redefined by variables
+    pub fn define_constant(
+        &mut self,
+        name: &str,
+        value: i128,
+        kind: SymbolKind,
+    ) -> Result<(), String> {
+        if kind.is_label() {
+            return Err("invalid symbol kind".to_string());
+        }
+        if let Some(symbol) = self.get(name) {
+            if !(symbol.kind() == SymbolKind::Variable && kind == SymbolKind::Variable) {
+                return Err("symbol redefined : ".to_string() + name);
+            }
+        }
+        self.insert(
+            name,
+            Symbol {
+                kind,
+                value,
+                section: None,
+            },
+        )
+    }
+
+    /// Define label at address in image assuming where its section starts
+    /// Address is moved to where the section is laid out by SymbolTable::relocate_labels
+    pub fn define_label(
+        &mut self,
+        name: &str,
+        section: usize,
+        address: usize,
+        executable: bool,
+    ) -> Result<(), String> {
+        if self.get(name).is_some() {
+            return Err("symbol redefined : ".to_string() + name);
+        }
+        let kind = if executable {
+            SymbolKind::CodeLabel
+        } else {
+            SymbolKind::DataLabel
+        };
+        self.insert(
+            name,
+            Symbol {
+                kind,
+                value: address as i128,
+                section: Some(section),
+            },
+        )
+    }
+
+    fn insert(&mut self, name: &str, symbol: Symbol) -> Result<(), String> {
+        if !is_keyword(name) || name.parse::<Register>().is_ok() {
+            return Err("invalid symbol name : ".to_string() + name);
+        }
+        self.symbols.insert(name.to_string(), symbol);
+        Ok(())
+    }
+
+    /// Assign new value to defined constant symbol
+    pub fn assign(&mut self, name: &str, value: i128) {
+        if let Some(symbol) = self.symbols.get_mut(name) {
+            if !symbol.kind.is_label() {
+                symbol.value = value;
+            }
+        }
+    }
+
+    /// Move labels from assumed addresses of their sections to addresses laid out
+    /// Sections missing in assumed are assumed to start at 0
+    pub fn relocate_labels(&mut self, assumed: &[usize], sections: &SectionTable) {
+        for symbol in self.symbols.values_mut() {
+            if let Some(section) = symbol.section {
+                let from = assumed.get(section).copied().unwrap_or(0);
+                symbol.value += sections.get(section).address() as i128 - from as i128;
+            }
+        }
+    }
+
+    /// Get current location which `$` refers
+    pub fn location(&self) -> usize {
+        self.location
+    }
+
+    /// Set current location which `$` refers
+    pub fn set_location(&mut self, location: usize) {
+        self.location = location;
+    }
+
+    /// Set start of current section which `$$` refers
+    pub fn set_section_start(&mut self, section_start: usize) {
+        self.section_start = section_start;
+    }
+
+    /// Evaluate expression with symbols and current location
+    pub fn evaluate(&self, expr: &str) -> Result<i128, String> {
+        expression::evaluate(expr, |name| match name {
+            "$" => Some(self.location as i128),
+            "$$" => Some(self.section_start as i128),
+            _ => self.value(name),
+        })
+    }
+
+    /// Replace constant symbols in operands of instruction with their values
+    pub fn substitute_constants(&self, line: &str) -> String {
+        let mut substituted = String::with_capacity(line.len());
+        let mut chars = line.char_indices().peekable();
+        let mut is_mnemonic = true;
+        let mut previous = ' ';
+
+        while let Some((start, c)) = chars.next() {
+            if !is_keyword_char(c) || c == '$' {
+                substituted.push(c);
+                previous = c;
+                continue;
+            }
+
+            let mut end = start + c.len_utf8();
+            while let Some((i, c)) = chars.peek() {
+                if is_keyword_char(*c) {
+                    end = i + c.len_utf8();
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            let word = &line[start..end];
+
+            // Size suffix of memory operands follows ']'
+            match self.constant(word) {
+                Some(value) if !is_mnemonic && previous != ']' && is_keyword(word) => {
+                    substituted += &value.to_string()
+                }
+                _ => substituted += word,
+            }
+            is_mnemonic = false;
+            previous = c;
+        }
+
+        substituted
+    }
+}
@@ -0,0 +1,77 @@
+use crate::instruction::INSTRUCTION_LIST;
+
+/// AT&T syntax front end
+mod att;
+
+/// NASM syntax front end
+mod nasm;
+
+/// Syntax of assembly source
+/// Instructions in other syntax are translated into Intel syntax before matching
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Syntax {
+    /// Intel syntax like `mov qword ptr [rbp + 8], rax`
+    #[default]
+    Intel,
+    /// AT&T syntax like `movq %rax, 8(%rbp)`
+    Att,
+    /// NASM syntax like `mov qword [rbp + 8], rax` and `db 0FFh`
+    Nasm,
+}
+
+impl Syntax {
+    /// Translate instruction into Intel syntax
+    /// # Example
+    /// ```
+    /// use asm::syntax::Syntax;
+    /// assert_eq!(
+    ///     Ok("mov qword ptr [rbp + rcx*4 + 8], rdi".to_string()),
+    ///     Syntax::Att.translate_instruction("movq %rdi, 8(%rbp,%rcx,4)")
+    /// );
+    /// ```
+    pub fn translate_instruction(self, line: &str) -> Result<String, String> {
+        match self {
+            Syntax::Intel => Ok(line.to_string()),
+            Syntax::Att => att::translate_instruction(line),
+            Syntax::Nasm => Ok(line.to_string()),
+        }
+    }
+
+    /// Translate line of source into lines which preprocessor understands
+    /// Lines of NASM are translated here since their directives differ from GNU syntax
+    /// `scope` is the previous non-local label of NASM, and local labels like `.loop` are renamed into `scope.loop`
+    /// # Example
+    /// ```
+    /// use asm::syntax::Syntax;
+    /// assert_eq!(
+    ///     Ok(vec!["message:".to_string(), ".byte 104, 105, 10".to_string()]),
+    ///     Syntax::Nasm.translate_line("message db 'hi', 0Ah", &mut String::new())
+    /// );
+    /// ```
+    pub fn translate_line(self, line: &str, scope: &mut String) -> Result<Vec<String>, String> {
+        match self {
+            Syntax::Intel | Syntax::Att => Ok(vec![line.to_string()]),
+            Syntax::Nasm => nasm::translate_line(line, scope),
+        }
+    }
+
+    /// Translate line whose defines are substituted by preprocessor
+    /// Values of NASM %define are kept as they are written, so strings in them are converted here
+    /// # Example
+    /// ```
+    /// use asm::syntax::Syntax;
+    /// assert_eq!(Ok(".byte 104, 105".to_string()), Syntax::Nasm.translate_substituted(".byte 'hi'"));
+    /// assert_eq!(Ok("mov eax, 26984".to_string()), Syntax::Nasm.translate_substituted("mov eax, 'hi'"));
+    /// ```
+    pub fn translate_substituted(self, line: &str) -> Result<String, String> {
+        match self {
+            Syntax::Intel | Syntax::Att => Ok(line.to_string()),
+            Syntax::Nasm => nasm::translate_substituted(line),
+        }
+    }
+}
+
+/// If this mnemonic is in INSTRUCTION_LIST
+fn is_mnemonic(mnemonic: &str) -> bool {
+    INSTRUCTION_LIST.iter().any(|i| i.mnemonic() == mnemonic)
+}
@@ -0,0 +1,159 @@
+use super::is_mnemonic;
+use crate::{
+    functions::{split_mnemonic, split_operands},
+    register::Register,
+};
+
+/// Mnemonics which have different names in AT&T syntax
+const MNEMONIC_ALIASES: [(&str, &str); 2] = [("movabs", "mov"), ("cqto", "cqo")];
+
+/// Shifts and rotates which shift by 1 if count is omitted
+const SHIFT_MNEMONICS: [&str; 8] = ["sal", "sar", "shl", "shr", "rol", "ror", "rcl", "rcr"];
+
+/// Translate AT&T instruction into Intel syntax
+pub fn translate_instruction(line: &str) -> Result<String, String> {
+    let (mnemonic, operands) = split_mnemonic(line);
+    let (mnemonic, size) = split_suffix(mnemonic);
+    let branch = mnemonic.starts_with('j') || mnemonic == "call" || mnemonic.starts_with("loop");
+    let operands = split_operands(operands);
+
+    // Count like `shlq %cl, %rax` is the only register which can differ from suffix
+    if let Some(size) = size {
+        let shift = SHIFT_MNEMONICS.contains(&mnemonic) || ["shld", "shrd"].contains(&mnemonic);
+        let count = usize::from(shift && 1 < operands.len());
+        let mismatch = operands[count..]
+            .iter()
+            .filter_map(|o| o.trim().strip_prefix('%')?.trim().parse::<Register>().ok())
+            .any(|r| !has_size(r, size));
+        if mismatch {
+            return Err("size suffix doesn't match register operands".to_string());
+        }
+    }
+
+    let mut translated = Vec::new();
+    for operand in operands.into_iter().rev() {
+        translated.push(translate_operand(operand, size, branch)?);
+    }
+    if translated.len() == 1 && SHIFT_MNEMONICS.contains(&mnemonic) {
+        translated.push("1".to_string());
+    }
+
+    if translated.is_empty() {
+        Ok(mnemonic.to_string())
+    } else {
+        Ok(format!("{} {}", mnemonic, translated.join(", ")))
+    }
+}
+
+/// Split size suffix from mnemonic
+/// Mnemonics which exist without removing suffix are kept as they are
+fn split_suffix(mnemonic: &str) -> (&str, Option<&'static str>) {
+    let alias = |m: &str| {
+        MNEMONIC_ALIASES
+            .iter()
+            .find(|(att, _)| *att == m)
+            .map(|(_, intel)| *intel)
+    };
+    if let Some(intel) = alias(mnemonic) {
+        return (intel, None);
+    }
+    if is_mnemonic(mnemonic) {
+        return (mnemonic, None);
+    }
+
+    let size = match mnemonic.chars().last() {
+        Some('b') => "byte",
+        Some('w') => "word",
+        Some('l') => "dword",
+        Some('q') => "qword",
+        _ => return (mnemonic, None),
+    };
+    let base = &mnemonic[..mnemonic.len() - 1];
+    match alias(base) {
+        Some(intel) => (intel, Some(size)),
+        None if is_mnemonic(base) => (base, Some(size)),
+        None => (mnemonic, None),
+    }
+}
+
+/// If register has size of suffix
+fn has_size(register: Register, size: &str) -> bool {
+    match size {
+        "byte" => register.operand_r8(),
+        "word" => register.operand_r16(),
+        "dword" => register.operand_r32(),
+        "qword" => register.operand_r64(),
+        _ => false,
+    }
+}
+
+fn translate_operand(operand: &str, size: Option<&str>, branch: bool) -> Result<String, String> {
+    let operand = operand.trim();
+    // Indirect branch target like `*%rax`
+    let operand = if branch {
+        operand.strip_prefix('*').unwrap_or(operand).trim()
+    } else {
+        operand
+    };
+
+    if let Some(register) = operand.strip_prefix('%') {
+        Ok(register.to_string())
+    } else if let Some(imm) = operand.strip_prefix('$') {
+        Ok(imm.to_string())
+    } else if operand.ends_with(')') {
+        translate_memory(operand, size)
+    } else if branch {
+        Ok(operand.to_string())
+    } else {
+        Err("absolute memory operand isn't supported : ".to_string() + operand)
+    }
+}
+
+/// Translate `disp(base, index, scale)` into `size ptr [base + index*scale + disp]`
+fn translate_memory(operand: &str, size: Option<&str>) -> Result<String, String> {
+    let error = || "invalid memory operand : ".to_string() + operand;
+    let (disp, reference) = operand
+        .strip_suffix(')')
+        .and_then(|o| o.rsplit_once('('))
+        .ok_or_else(error)?;
+
+    let register = |s: &str| -> Result<Option<String>, String> {
+        match s.trim() {
+            "" => Ok(None),
+            s => match s.strip_prefix('%') {
+                Some(r) => Ok(Some(r.trim().to_string())),
+                None => Err(error()),
+            },
+        }
+    };
+    let parts: Vec<&str> = reference.split(',').collect();
+    let mut terms = Vec::new();
+    match parts.as_slice() {
+        [base] => terms.extend(register(base)?),
+        [base, index] => {
+            terms.extend(register(base)?);
+            terms.extend(register(index)?);
+        }
+        [base, index, scale] => {
+            terms.extend(register(base)?);
+            let index = register(index)?.ok_or_else(error)?;
+            terms.push(format!("{}*{}", index, scale.trim()));
+        }
+        _ => return Err(error()),
+    }
+    let mut reference = terms.join(" + ");
+    match disp.trim() {
+        "" => (),
+        disp if terms.is_empty() => reference = disp.to_string(),
+        disp => match disp.strip_prefix('-') {
+            Some(disp) => reference = format!("{} - {}", reference, disp.trim()),
+            None => reference = format!("{} + {}", reference, disp),
+        },
+    }
+
+    let reference = format!("[{}]", reference);
+    Ok(match size {
+        Some(size) => format!("{} ptr {}", size, reference),
+        None => reference,
+    })
+}
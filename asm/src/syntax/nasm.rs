@@ -0,0 +1,528 @@
+use super::is_mnemonic;
+use crate::{
+    expression::Node,
+    functions::{is_keyword, is_quote, parse_string_literal, split_arguments, string_end},
+    preprocessor::split_statement,
+};
+
+/// Data commands and their commands and sizes in GNU syntax
+const DATA_COMMANDS: [(&str, &str, usize); 4] = [
+    ("db", ".byte", 1),
+    ("dw", ".word", 2),
+    ("dd", ".dword", 4),
+    ("dq", ".qword", 8),
+];
+
+/// Commands which reserve uninitialized space and sizes of their units
+const RESERVE_COMMANDS: [(&str, usize); 4] = [("resb", 1), ("resw", 2), ("resd", 4), ("resq", 8)];
+
+/// Max count of parameters of `%macro`
+const MAX_MACRO_PARAMETERS: usize = 64;
+
+/// Directives which don't change output of flat binary
+const IGNORED_DIRECTIVES: [&str; 5] = ["bits", "use64", "cpu", "global", "extern"];
+
+/// Preprocessor directives which have the same meaning as GNU commands
+const PREPROCESSOR_DIRECTIVES: [(&str, &str); 12] = [
+    ("%define", ".define"),
+    ("%xdefine", ".define"),
+    ("%undef", ".undef"),
+    ("%endmacro", ".endm"),
+    ("%rep", ".rept"),
+    ("%endrep", ".endr"),
+    ("%if", ".if"),
+    ("%elif", ".elseif"),
+    ("%else", ".else"),
+    ("%endif", ".endif"),
+    ("%ifdef", ".ifdef"),
+    ("%ifndef", ".ifndef"),
+];
+
+/// Preprocessor directives whose arguments are kept as they are
+/// Strings in values of %define are converted where they are used by translate_substituted
+const RAW_DIRECTIVES: [&str; 5] = ["%define", "%xdefine", "%undef", "%ifdef", "%ifndef"];
+
+/// Operator keywords which are only meaningful to NASM
+const OPERAND_KEYWORDS: [&str; 5] = ["short", "near", "strict", "abs", "rel"];
+
+/// Translate NASM line into lines which preprocessor and parser understand
+/// `scope` is the previous non-local label, which local labels like `.loop` belong to
+pub fn translate_line(line: &str, scope: &mut String) -> Result<Vec<String>, String> {
+    let line = convert_words(line.trim())?;
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(vec![String::new()]);
+    }
+
+    // Directives like `[bits 64]`
+    if let Some(directive) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+        return translate_line(directive, scope);
+    }
+    if line.starts_with('%') {
+        return translate_preprocessor_directive(line).map(|line| vec![line]);
+    }
+
+    // Label followed by statement like `start: mov rax, 1`
+    if let Some((label, rest)) = line.split_once(':') {
+        if is_label_name(label) {
+            let mut lines = vec![define_label(label, scope) + ":"];
+            if !rest.trim().is_empty() {
+                lines.extend(translate_line(rest, scope)?);
+            }
+            return Ok(lines);
+        }
+    }
+
+    let (name, arguments) = split_statement(line);
+    let (second, rest) = split_statement(arguments);
+    if second == "equ" {
+        return Ok(vec![format!(
+            ".equ {}, {}",
+            scope_local_labels(name, scope),
+            convert_characters(&scope_local_labels(rest, scope))?
+        )]);
+    }
+    // Label without colon like `message db "hello"`
+    if is_label_name(name) && !is_directive(name) && is_data_statement(second) {
+        let mut lines = vec![define_label(name, scope) + ":"];
+        lines.extend(translate_line(arguments, scope)?);
+        return Ok(lines);
+    }
+
+    // Section names like `.text` aren't local labels
+    let arguments = if name == "section" || name == "segment" {
+        arguments.to_string()
+    } else {
+        scope_local_labels(arguments, scope)
+    };
+    match translate_statement(name, &arguments, scope)? {
+        Some(lines) => Ok(lines),
+        None => Ok(vec![scope_local_labels(line, scope)]),
+    }
+}
+
+/// Get name of label defined in line
+/// Non-local labels become the scope of the following local labels
+fn define_label(label: &str, scope: &mut String) -> String {
+    let label = label.trim();
+    // Labels from macros like `%%skip` don't change scope
+    if !label.starts_with('.') && !label.contains('\\') {
+        *scope = label.to_string();
+    }
+    scope_local_labels(label, scope)
+}
+
+/// Rename local labels like `.loop` into `func.loop`, where `func` is scope
+/// Strings are kept as they are
+fn scope_local_labels(expr: &str, scope: &str) -> String {
+    let mut scoped = String::with_capacity(expr.len() + scope.len());
+    let mut chars = expr.char_indices().peekable();
+    let mut previous = ' ';
+
+    while let Some((start, c)) = chars.next() {
+        if is_quote(c) {
+            let end = string_end(expr, start).unwrap_or(expr.len());
+            scoped += &expr[start..end];
+            while chars.peek().is_some_and(|(i, _)| *i < end) {
+                chars.next();
+            }
+        } else {
+            let is_name_char = |c: char| c.is_ascii_alphanumeric() || "_.\\@$".contains(c);
+            if c == '.'
+                && !is_name_char(previous)
+                && chars
+                    .peek()
+                    .is_some_and(|(_, c)| c.is_ascii_alphabetic() || *c == '_')
+            {
+                scoped += scope;
+            }
+            scoped.push(c);
+        }
+        previous = c;
+    }
+
+    scoped
+}
+
+/// Translate statement which isn't preprocessor directive
+/// Returns None for instructions and macro calls
+fn translate_statement(
+    name: &str,
+    arguments: &str,
+    scope: &mut String,
+) -> Result<Option<Vec<String>>, String> {
+    let lines = if let Some((_, command, size)) = DATA_COMMANDS.iter().find(|d| d.0 == name) {
+        vec![translate_data(command, *size, arguments)?]
+    } else if let Some((_, size)) = RESERVE_COMMANDS.iter().find(|r| r.0 == name) {
+        vec![format!(
+            ".skip ({}) * {}",
+            convert_characters(arguments)?,
+            size
+        )]
+    } else {
+        match name {
+            "times" => translate_times(arguments, scope)?,
+            "section" | "segment" => {
+                let (section, _) = split_statement(arguments);
+                vec![".section ".to_string() + section]
+            }
+            "align" | "alignb" => {
+                let align = split_arguments(arguments).first().copied().unwrap_or("");
+                vec![".balign ".to_string() + align]
+            }
+            "incbin" => vec![".incbin ".to_string() + &convert_file_name(arguments)?],
+            "org" => return Err("org isn't supported".to_string()),
+            // `default rel` would make bare `[label]` operands rip relative
+            "default" if arguments.trim() == "abs" => Vec::new(),
+            "default" => return Err(format!("default {} isn't supported", arguments.trim())),
+            _ if IGNORED_DIRECTIVES.contains(&name) => Vec::new(),
+            _ if is_mnemonic(name) => vec![translate_instruction(name, arguments)?],
+            _ => return Ok(None),
+        }
+    };
+    Ok(Some(lines))
+}
+
+fn translate_preprocessor_directive(line: &str) -> Result<String, String> {
+    let (name, arguments) = split_statement(line);
+
+    if let Some((_, command)) = PREPROCESSOR_DIRECTIVES.iter().find(|d| d.0 == name) {
+        let arguments = if RAW_DIRECTIVES.contains(&name) {
+            arguments.to_string()
+        } else {
+            convert_characters(arguments)?
+        };
+        return Ok(format!("{} {}", command, arguments).trim_end().to_string());
+    }
+    match name {
+        "%assign" => {
+            let (symbol, value) = split_statement(arguments);
+            Ok(format!(".set {}, {}", symbol, convert_characters(value)?))
+        }
+        "%macro" => translate_macro(arguments),
+        "%include" => Ok(".include ".to_string() + &convert_file_name(arguments)?),
+        _ => Err("unknown directive : ".to_string() + name),
+    }
+}
+
+/// Translate line whose defines are substituted
+/// Strings from values of %define are converted like `db` and other statements convert them
+pub fn translate_substituted(line: &str) -> Result<String, String> {
+    let (name, arguments) = split_statement(line);
+    if let Some((_, command, size)) = DATA_COMMANDS.iter().find(|d| d.1 == name) {
+        translate_data(command, *size, arguments)
+    } else if name == ".include" || name == ".incbin" {
+        Ok(line.to_string())
+    } else {
+        convert_characters(line)
+    }
+}
+
+/// Translate `%macro name count` or `%macro name min-max`
+/// Parameters are named as p1, p2, ... and `%1` is translated into `\p1`
+fn translate_macro(arguments: &str) -> Result<String, String> {
+    let (name, count) = split_statement(arguments);
+    let (count, _) = split_statement(count);
+    let max = match count.split_once('-') {
+        Some((_, max)) => max,
+        None => count,
+    };
+    let max: usize = max
+        .parse()
+        .ok()
+        .filter(|max| *max <= MAX_MACRO_PARAMETERS)
+        .ok_or_else(|| "invalid parameter count : ".to_string() + count)?;
+
+    let parameters: Vec<String> = (1..=max).map(|i| format!("p{}", i)).collect();
+    Ok(format!(".macro {} {}", name, parameters.join(", "))
+        .trim_end()
+        .to_string())
+}
+
+/// Translate `db`, `dw`, `dd` and `dq`
+/// Strings are split into units of the data size
+fn translate_data(command: &str, size: usize, arguments: &str) -> Result<String, String> {
+    let mut values = Vec::new();
+
+    for argument in split_arguments(arguments) {
+        if argument.is_empty() {
+            return Err("invalid data : ".to_string() + arguments);
+        }
+        if argument == "?" {
+            values.push("0".to_string());
+        } else if argument.starts_with(is_quote) {
+            let bytes = parse_string(argument).ok_or("invalid string : ".to_string() + argument)?;
+            for unit in bytes.chunks(size) {
+                values.push(little_endian(unit).to_string());
+            }
+        } else {
+            values.push(convert_characters(argument)?);
+        }
+    }
+
+    Ok(format!("{} {}", command, values.join(", ")))
+}
+
+/// Translate `times count statement`
+/// Data of one value is filled by .fill, and others are repeated by .rept
+fn translate_times(arguments: &str, scope: &mut String) -> Result<Vec<String>, String> {
+    let start = statement_start(arguments).ok_or("invalid times : ".to_string() + arguments)?;
+    let count = convert_characters(arguments[..start].trim())?;
+    let (name, rest) = split_statement(&arguments[start..]);
+
+    if let Some((_, _, size)) = DATA_COMMANDS.iter().find(|d| d.0 == name) {
+        if let [value] = split_arguments(rest).as_slice() {
+            if !value.starts_with(is_quote) {
+                let value = if *value == "?" { "0" } else { value };
+                return Ok(vec![format!(".fill {}, {}, {}", count, size, value)]);
+            }
+        }
+    }
+
+    let mut lines = vec![".rept ".to_string() + &count];
+    lines.extend(translate_line(&arguments[start..], scope)?);
+    lines.push(".endr".to_string());
+    Ok(lines)
+}
+
+/// Translate instruction
+/// NASM operand keywords are removed and `[rel label]` is translated into `[rip + label]`
+fn translate_instruction(mnemonic: &str, operands: &str) -> Result<String, String> {
+    let operands = convert_characters(operands)?;
+    let mut translated = String::with_capacity(operands.len());
+    let mut rest = operands.as_str();
+
+    while !rest.is_empty() {
+        let word_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        if word_len == 0 {
+            let c = rest.chars().next().unwrap_or(' ');
+            translated.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        let word = &rest[..word_len];
+        rest = &rest[word_len..];
+        match word {
+            "rel" if translated.trim_end().ends_with('[') => translated += "rip +",
+            _ if OPERAND_KEYWORDS.contains(&word) => (),
+            _ => translated += word,
+        }
+    }
+
+    Ok(format!("{} {}", mnemonic, translated.trim())
+        .trim_end()
+        .to_string())
+}
+
+/// Convert numbers like `0FFh`, `1010b` and `0q17` into decimal, and macro parameters like `%1`
+/// Strings are kept as they are
+fn convert_words(line: &str) -> Result<String, String> {
+    let mut converted = String::with_capacity(line.len());
+    let mut chars = line.char_indices().peekable();
+    let mut previous = ' ';
+
+    while let Some((start, c)) = chars.next() {
+        if is_quote(c) {
+            let end = string_end(line, start).unwrap_or(line.len());
+            converted += &line[start..end];
+            while chars.peek().is_some_and(|(i, _)| *i < end) {
+                chars.next();
+            }
+            previous = c;
+            continue;
+        }
+
+        let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        let word_end = |chars: &mut std::iter::Peekable<std::str::CharIndices>| {
+            let mut end = start + c.len_utf8();
+            while let Some((i, c)) = chars.peek().copied() {
+                if !is_word(c) {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            end
+        };
+
+        if c == '%' && chars.peek().is_some_and(|(_, c)| *c == '%') {
+            // Macro local label like `%%skip`
+            chars.next();
+            converted += "L\\@";
+        } else if c == '%' && chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) {
+            converted += "\\p";
+        } else if (c.is_ascii_digit() || is_dollar_hex(c, chars.peek().map(|(_, c)| *c)))
+            && !is_word(previous)
+            && previous != '\\'
+        {
+            let word = &line[start..word_end(&mut chars)];
+            let value = parse_number(word).ok_or("invalid number : ".to_string() + word)?;
+            converted += &value.to_string();
+            previous = '0';
+            continue;
+        } else if is_word(c) {
+            converted += &line[start..word_end(&mut chars)];
+        } else {
+            converted.push(c);
+        }
+        previous = c;
+    }
+
+    Ok(converted)
+}
+
+/// If `$` followed by next starts hex number like `$0FF`
+fn is_dollar_hex(c: char, next: Option<char>) -> bool {
+    c == '$' && next.is_some_and(|c| c.is_ascii_digit())
+}
+
+/// Parse NASM number like `0FFh`, `0xff`, `$0FF`, `1010b`, `0b1010`, `17q`, `0o17` or `1_000`
+fn parse_number(word: &str) -> Option<i128> {
+    const PREFIXES: [(&str, u32); 9] = [
+        ("0x", 16),
+        ("$", 16),
+        ("0h", 16),
+        ("0b", 2),
+        ("0y", 2),
+        ("0o", 8),
+        ("0q", 8),
+        ("0d", 10),
+        ("0t", 10),
+    ];
+    const SUFFIXES: [(char, u32); 8] = [
+        ('h', 16),
+        ('b', 2),
+        ('y', 2),
+        ('o', 8),
+        ('q', 8),
+        ('d', 10),
+        ('t', 10),
+        ('x', 16),
+    ];
+
+    let word = word.replace('_', "").to_ascii_lowercase();
+    let parse = |digits: &str, radix| {
+        if digits.is_empty() {
+            None
+        } else {
+            i128::from_str_radix(digits, radix).ok()
+        }
+    };
+
+    if let Some(value) = PREFIXES
+        .iter()
+        .find_map(|(prefix, radix)| parse(word.strip_prefix(prefix)?, *radix))
+    {
+        return Some(value);
+    }
+    if let Some(value) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, radix)| parse(word.strip_suffix(*suffix)?, *radix))
+    {
+        return Some(value);
+    }
+    parse(&word, 10)
+}
+
+/// Convert character constants like `'ab'` into numbers in little endian
+fn convert_characters(expr: &str) -> Result<String, String> {
+    let mut converted = String::with_capacity(expr.len());
+    let mut rest = expr;
+
+    while let Some(start) = rest.find(is_quote) {
+        converted += &rest[..start];
+        let end = string_end(rest, start).ok_or("unclosed string : ".to_string() + expr)?;
+        let literal = &rest[start..end];
+        let bytes = parse_string(literal).ok_or("invalid string : ".to_string() + literal)?;
+        if 8 < bytes.len() {
+            return Err("character constant too long : ".to_string() + literal);
+        }
+        converted += &little_endian(&bytes).to_string();
+        rest = &rest[end..];
+    }
+    converted += rest;
+
+    Ok(converted)
+}
+
+/// Convert file name in `'file'` or `"file"` into string literal of GNU syntax
+fn convert_file_name(arguments: &str) -> Result<String, String> {
+    let error = || "invalid file name : ".to_string() + arguments;
+    let mut arguments = split_arguments(arguments).into_iter();
+    let name = arguments.next().ok_or_else(error)?;
+    let name = if name.starts_with(is_quote) {
+        parse_string(name).ok_or_else(error)?
+    } else {
+        name.as_bytes().to_vec()
+    };
+    let name = String::from_utf8(name).map_err(|_| error())?;
+
+    let mut converted = vec![format!("\"{}\"", name.escape_default())];
+    converted.extend(arguments.map(|a| a.to_string()));
+    Ok(converted.join(", "))
+}
+
+/// Parse string in quotes
+/// Escape sequences are only available in backquotes
+fn parse_string(literal: &str) -> Option<Vec<u8>> {
+    let quote = literal.chars().next().filter(|c| is_quote(*c))?;
+    let inner = literal.strip_prefix(quote)?.strip_suffix(quote)?;
+    if quote == '`' {
+        parse_string_literal(&format!("\"{}\"", inner.replace('"', "\\\"")))
+    } else if inner.contains(quote) {
+        None
+    } else {
+        Some(inner.as_bytes().to_vec())
+    }
+}
+
+fn little_endian(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .rev()
+        .fold(0, |value, byte| (value << 8) | *byte as u64)
+}
+
+/// Find start of statement repeated by times
+/// Statement is the first word after an expression of count, so it may be any mnemonic or macro
+fn statement_start(arguments: &str) -> Option<usize> {
+    let mut previous = ' ';
+    for (i, c) in arguments.char_indices() {
+        if 0 < i && (previous.is_whitespace() || previous == ')') {
+            let (word, _) = split_statement(&arguments[i..]);
+            if is_keyword(word) && Node::parse(&arguments[..i]).is_ok() {
+                return Some(i);
+            }
+        }
+        previous = c;
+    }
+    None
+}
+
+fn is_data_statement(word: &str) -> bool {
+    DATA_COMMANDS.iter().any(|d| d.0 == word)
+        || RESERVE_COMMANDS.iter().any(|r| r.0 == word)
+        || word == "times"
+        || word == "equ"
+}
+
+fn is_directive(word: &str) -> bool {
+    is_data_statement(word)
+        || is_mnemonic(word)
+        || IGNORED_DIRECTIVES.contains(&word)
+        || [
+            "section", "segment", "align", "alignb", "incbin", "org", "default",
+        ]
+        .contains(&word)
+}
+
+fn is_label_name(word: &str) -> bool {
+    let word = word.trim();
+    word.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '.')
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '\\' || c == '@')
+}
@@ -0,0 +1,33 @@
+#![no_main]
+
+use asm::{syntax::Syntax, Asm};
+use libfuzzer_sys::fuzz_target;
+
+/// NASM source which input is appended to, since random bytes rarely reach % directives
+const NASM_SEED: &str = "%define N 2
+%macro m 1-2
+    times N db %1
+%endmacro
+section .text
+start: m 0FFh
+    mov [rel start], rax
+";
+
+// Any source must be assembled or rejected with diagnostics without panic
+fuzz_target!(|data: &[u8]| {
+    let Ok(source) = std::str::from_utf8(data) else {
+        return;
+    };
+    let seeded = NASM_SEED.to_string() + source;
+    for (source, syntax) in [
+        (source, Syntax::Intel),
+        (source, Syntax::Att),
+        (source, Syntax::Nasm),
+        (seeded.as_str(), Syntax::Nasm),
+    ] {
+        let asm = Asm::new(source).with_syntax(syntax);
+        if let Ok((assembly, _)) = asm.assemble_with_diagnostics() {
+            assembly.image();
+        }
+    }
+});
@@ -9,7 +9,7 @@ fn main() {
         ret";
 
     let asm = Asm::new(source);
-    let code = asm.assemble().unwrap();
+    let code = asm.assemble().unwrap().image();
     let dynfn = DynFn::<u64, u64>::new(&code);
     let value = unsafe { dynfn.call(13) };
     println!("{:?}", value);
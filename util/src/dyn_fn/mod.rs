@@ -5,6 +5,7 @@ use std::{
     ops::Drop,
 };
 
+#[allow(clippy::module_inception)]
 mod mem_exec;
 
 /// DynFn is a struct for dynamic code execulution
@@ -50,8 +51,8 @@ impl<T, U> DynFn<T, U> {
 
         unsafe {
             ptr = alloc(layout);
-            for i in 0..code.len() {
-                ptr.add(i).write_bytes(code[i], 1);
+            for (i, c) in code.iter().enumerate() {
+                ptr.add(i).write_bytes(*c, 1);
             }
 
             mem_executable(ptr, code.len()).unwrap();
@@ -59,14 +60,12 @@ impl<T, U> DynFn<T, U> {
             fnptr = transmute::<*mut u8, DynFnPtr<T, U>>(ptr);
         }
 
-        DynFn {
-            layout: layout,
-            fnptr: fnptr,
-            ptr: ptr,
-        }
+        DynFn { layout, fnptr, ptr }
     }
 
     /// Execute DynFn
+    /// # Safety
+    /// The code must be a valid function for the sysv64 ABI taking T and returning U.
     pub unsafe fn call(&self, arg: T) -> U {
         (self.fnptr)(arg)
     }
@@ -43,9 +43,9 @@ fn stoi_helper(s: &str, n: &[char]) -> Option<i128> {
             return None;
         }
 
-        for i in 0..n.len() {
-            if c == n[i] {
-                num += i as i128;
+        for (i, d) in n.iter().enumerate() {
+            if c == *d {
+                num = num.checked_add(i as i128)?;
                 match_flag = true;
                 break;
             }
@@ -59,7 +59,11 @@ fn stoi_helper(s: &str, n: &[char]) -> Option<i128> {
 }
 
 fn stoi_minus(s: &str) -> Option<i128> {
-    stoi(remove_prefix(s, "-")?.trim()).map(|v| -v)
+    let s = remove_prefix(s, "-")?.trim();
+    if s.starts_with('-') {
+        return None;
+    }
+    stoi(s).map(|v| -v)
 }
 
 /// Binary to Integer
@@ -105,10 +109,41 @@ pub fn get_inner_bracket(mut expr: &str, bracket_start: char, bracket_end: char)
 
 /// Convert Result<T, E> to Option<T>
 pub fn result_to_option<T, E>(result: Result<T, E>) -> Option<T> {
-    match result {
-        Ok(t) => Some(t),
-        Err(_) => None,
+    result.ok()
+}
+
+/// Edit distance between strings
+/// Insertions, deletions, substitutions and transpositions of adjacent characters cost 1
+/// # Example
+/// ```
+/// use util::functions::edit_distance;
+/// assert_eq!(0, edit_distance("push", "push"));
+/// assert_eq!(1, edit_distance("psh", "push"));
+/// assert_eq!(1, edit_distance("mvo", "mov"));
+/// assert_eq!(2, edit_distance("rxa", "rbx"));
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
     }
+    distances[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if 1 < i && 1 < j && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
 }
 
 /// Matching string
@@ -129,7 +164,7 @@ pub fn match_str<'a>(mut s: &'a str, rule: &[MatchStr<'_>]) -> Option<Vec<&'a st
         next_rule: Option<&MatchStr<'_>>,
         matching_fn: impl Fn(&str) -> bool,
     ) -> Option<(&'b str, &'b str)> {
-        let mut left = s.split_ascii_whitespace().next().or(Some("")).unwrap();
+        let mut left = s.split_ascii_whitespace().next().unwrap_or("");
 
         if let Some(MatchStr::Char(c)) = next_rule {
             left = s.split(*c).next()?;
@@ -220,22 +255,26 @@ mod test {
         assert_eq!(0xfe, stoi("0xfe").unwrap());
         assert_eq!(0b101, stoi("0b101").unwrap());
         assert_eq!(0o132, stoi("0o132").unwrap());
+        assert_eq!(-5, stoi("-5").unwrap());
+        assert_eq!(
+            i128::MAX,
+            stoi("170141183460469231731687303715884105727").unwrap()
+        );
+        assert_eq!(None, stoi("170141183460469231731687303715884105728"));
+        assert_eq!(None, stoi("--5"));
     }
 
     #[test]
     pub fn match_str_test() {
-        fn is_reg64(s: &str) -> bool {
-            s == "rbp" || s == "rdi"
-        }
         assert_eq!(
             Some(vec!["[", "rbp", "+", "rdi", "*", "2", "]"]),
             match_str(
-                &"[ rbp + rdi * 2 ]",
+                "[ rbp + rdi * 2 ]",
                 &[
                     MatchStr::Char('['),
-                    MatchStr::Str(&"rbp"),
+                    MatchStr::Str("rbp"),
                     MatchStr::Char('+'),
-                    MatchStr::Str(&"rdi"),
+                    MatchStr::Str("rdi"),
                     MatchStr::Char('*'),
                     MatchStr::Number,
                     MatchStr::Char(']'),
@@ -244,6 +283,15 @@ mod test {
         );
     }
 
+    #[test]
+    pub fn edit_distance_test() {
+        assert_eq!(3, edit_distance("", "rax"));
+        assert_eq!(3, edit_distance("rax", ""));
+        assert_eq!(1, edit_distance("rcxx", "rcx"));
+        assert_eq!(1, edit_distance("strat", "start"));
+        assert_eq!(3, edit_distance("kitten", "sitting"));
+    }
+
     #[test]
     pub fn get_inner_expr_test() {
         assert_eq!("Hello", get_inner_expr(" [Hello ]", ['[', ']']).unwrap());
@@ -12,7 +12,7 @@ use std::{
 /// - Faster than Vec
 /// - Only for copy type
 /// - For small use
-
+///
 /// # Examples
 /// ```
 /// use util::svec::SVec;
@@ -49,13 +49,42 @@ impl<const C: usize, T: Copy + Default> SVec<C, T> {
     }
 
     /// Push value to SVec
+    /// Panics if SVec is full
     pub fn push(&mut self, value: T) -> &mut SVec<C, T> {
+        self.try_push(value).expect("max length")
+    }
+
+    /// Push value to SVec
+    /// Returns None without pushing if SVec is full
+    pub fn try_push(&mut self, value: T) -> Option<&mut SVec<C, T>> {
         if self.len() == C {
-            panic!("max length")
+            None
         } else {
             self.len += 1;
             self.array[self.len - 1] = value;
-            self
+            Some(self)
+        }
+    }
+
+    /// Append values of other SVec
+    /// Returns None without appending if capacity is not enough
+    /// # Example
+    /// ```
+    /// use util::svec::SVec;
+    /// let mut svec: SVec<3, u8> = SVec::from([1, 2]);
+    ///
+    /// assert!(svec.try_append(SVec::<2, u8>::from([3])).is_some());
+    /// assert!(svec.try_append(SVec::<2, u8>::from([4])).is_none());
+    /// assert_eq!(svec, SVec::<3, u8>::from([1, 2, 3]));
+    /// ```
+    pub fn try_append<const D: usize>(&mut self, other: SVec<D, T>) -> Option<&mut SVec<C, T>> {
+        if C < self.len() + other.len() {
+            None
+        } else {
+            for v in other {
+                self.push(v);
+            }
+            Some(self)
         }
     }
 
@@ -65,7 +94,7 @@ impl<const C: usize, T: Copy + Default> SVec<C, T> {
         let size = size_of::<U>();
         let t_size = size_of::<T>();
 
-        if size % t_size != 0 {
+        if !size.is_multiple_of(t_size) {
             panic!("invalid type");
         }
 
@@ -74,12 +103,12 @@ impl<const C: usize, T: Copy + Default> SVec<C, T> {
             let v: T = unsafe { *(ptr.add(i)) };
             self.push(v);
         }
-        panic!()
+        self
     }
 
     /// Pop value to SVec
     pub fn pop(&mut self) -> T {
-        if self.len() == 0 {
+        if self.is_empty() {
             panic!("zero length")
         } else {
             let elm = self[self.len() - 1];
@@ -114,6 +143,11 @@ impl<const C: usize, T: Copy + Default> SVec<C, T> {
         self.len
     }
 
+    /// If SVec has no elements
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Resize buffer
     pub fn resize_buff<const D: usize>(self) -> SVec<D, T> {
         if D < self.len {
@@ -142,10 +176,7 @@ impl<const C: usize, T: Copy + Default> SVec<C, T> {
 
     /// Create SVec statically from array
     pub const fn from_raw(arr: [T; C], len: usize) -> SVec<C, T> {
-        SVec {
-            array: arr,
-            len: len,
-        }
+        SVec { array: arr, len }
     }
 
     pub fn push_to(self, vec: &mut Vec<T>) {
@@ -161,7 +192,7 @@ impl<const C: usize> SVec<C, u8> {
         if C <= 8 && len <= C {
             let mut svec = SVec::new();
             for i in 0..len {
-                svec.push(((value >> i * 8) & 0xff) as u8);
+                svec.push(((value >> (i * 8)) & 0xff) as u8);
             }
             svec
         } else {
@@ -170,6 +201,12 @@ impl<const C: usize> SVec<C, u8> {
     }
 }
 
+impl<const C: usize, T: Copy + Default> Default for SVec<C, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const C: usize, T: Copy + Default> Deref for SVec<C, T> {
     type Target = [T];
     fn deref(&self) -> &[T] {
@@ -185,13 +222,7 @@ impl<const C: usize, T: Copy + Default> DerefMut for SVec<C, T> {
 
 impl<const C: usize, const D: usize, T: Copy + Default> AddAssign<SVec<D, T>> for SVec<C, T> {
     fn add_assign(&mut self, rhs: SVec<D, T>) {
-        if C <= self.len() + rhs.len() {
-            panic!("buffer overflow");
-        }
-
-        for v in rhs {
-            self.push(v);
-        }
+        self.try_append(rhs).expect("buffer overflow");
     }
 }
 
@@ -262,8 +293,8 @@ impl<const C: usize, T: Copy + Default> From<&[T]> for SVec<C, T> {
         }
 
         let mut new_svec = SVec::new();
-        for i in 0..value.len() {
-            new_svec.push(value[i]);
+        for v in value {
+            new_svec.push(*v);
         }
         new_svec
     }
@@ -276,8 +307,8 @@ impl<const C: usize, const L: usize, T: Copy + Default> From<[T; L]> for SVec<C,
         }
 
         let mut new_svec = SVec::new();
-        for i in 0..L {
-            new_svec.push(value[i]);
+        for v in value {
+            new_svec.push(v);
         }
         new_svec
     }
@@ -317,10 +348,7 @@ pub struct SVecIterator<const C: usize, T: Copy + Default> {
 
 impl<const C: usize, T: Copy + Default> SVecIterator<C, T> {
     pub fn new(svec: SVec<C, T>) -> Self {
-        SVecIterator {
-            svec: svec,
-            index: 0,
-        }
+        SVecIterator { svec, index: 0 }
     }
 }
 